@@ -0,0 +1,218 @@
+#![allow(warnings, unused)]
+use std::io;
+use std::fs;
+use std::path::Path;
+use std::collections::{HashMap, HashSet};
+
+use crate::slugger::Slugger;
+use crate::lexer::{ILexer, Lexer};
+use crate::parser::{heading_text, register_heading_ids};
+use crate::marked::collect_tokens;
+use crate::regex::{RegexHelper, regx_helper};
+use crate::source_map::{locate_tokens, SourceMap, Span};
+use crate::defaults::{get_default_options, Options};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Problem {
+    // The linked file is not part of the checked set or does not exist
+    MissingFile,
+    // The linked file has no heading or element with the fragment as id
+    MissingAnchor
+}
+
+// What is known about a link target
+enum Target {
+    Missing,
+    // Exists, but its anchors are not known
+    Unchecked,
+    Document(HashSet<String>)
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct BrokenLink {
+    pub path: String,
+    pub href: String,
+    pub problem: Problem,
+    pub span: Span
+}
+
+/**
+ * Ids a document exposes as link targets: heading ids as the renderer
+ * generates them, and `id`/`name` attributes of raw HTML
+ */
+pub fn anchor_ids(src: &str, opt: Option<Options>) -> HashSet<String> {
+    let options = if opt.is_some() { opt.unwrap() } else { get_default_options() };
+
+    let mut lexer = Lexer::new(options);
+    let tokens = lexer.lex(src);
+
+    let mut ids: HashSet<String> = HashSet::new();
+    let mut slugger = Slugger::new();
+    register_heading_ids(tokens, &mut slugger);
+
+    let mut headings = vec![];
+    collect_tokens(tokens, "heading", &mut headings);
+
+    for heading in headings.iter() {
+        let t = heading.as_ref().borrow();
+        let explicit_id = t.attrs.iter().find(|(k, _)| k == "id");
+
+        if explicit_id.is_some() {
+            ids.insert(explicit_id.unwrap().1.clone());
+        } else if options.header_ids {
+            let slug = slugger.slug(heading_text(t.tokens.clone(), options).as_str(), false);
+            ids.insert(format!("{}{}", options.header_prefix, slug));
+        }
+    }
+
+    let mut html = vec![];
+    collect_tokens(tokens, "html", &mut html);
+
+    for token in html.iter() {
+        for caps in regx_helper(RegexHelper::HtmlIdAttribute).captures_iter(token.as_ref().borrow().raw.as_str()) {
+            let id = caps.get(1).or(caps.get(2)).or(caps.get(3)).map_or("", |m| m.as_str());
+            ids.insert(id.to_string());
+        }
+    }
+    ids
+}
+
+/**
+ * Check fragment links and relative links between `documents`, given as
+ * `(path, markdown)` pairs with `/`-separated paths relative to a common root.
+ * Links to other Markdown files must point into the set; links to any other
+ * kind of file are not checked
+ */
+pub fn check_documents(documents: &[(&str, &str)], opt: Option<Options>) -> Vec<BrokenLink> {
+    check(documents, opt, &|path, _| if is_markdown(path) { Target::Missing } else { Target::Unchecked })
+}
+
+/**
+ * Read and check the given Markdown files. Relative links to files outside
+ * the set are checked against the file system, and fragments of links to
+ * Markdown files outside the set against the ids of those files
+ */
+pub fn check_files<P: AsRef<Path>>(paths: &[P], opt: Option<Options>) -> io::Result<Vec<BrokenLink>> {
+    let mut contents: Vec<(String, String)> = vec![];
+    for path in paths.iter() {
+        let path = path.as_ref().to_string_lossy().replace('\\', "/");
+        let src = fs::read_to_string(&path)?;
+        contents.push((path, src));
+    }
+
+    let documents: Vec<(&str, &str)> = contents.iter()
+        .map(|(path, src)| (path.as_str(), src.as_str()))
+        .collect();
+
+    Ok(check(&documents, opt, &|path, options| {
+        if !Path::new(path).exists() {
+            Target::Missing
+        } else if is_markdown(path) {
+            fs::read_to_string(path).map_or(Target::Unchecked, |src| Target::Document(anchor_ids(src.as_str(), Some(*options))))
+        } else {
+            Target::Unchecked
+        }
+    }))
+}
+
+fn check(documents: &[(&str, &str)], opt: Option<Options>, lookup: &dyn Fn(&str, &Options) -> Target) -> Vec<BrokenLink> {
+    let options = if opt.is_some() { opt.unwrap() } else { get_default_options() };
+
+    let mut targets: HashMap<String, Target> = documents.iter()
+        .map(|(path, src)| (normalize_path(path), Target::Document(anchor_ids(src, Some(options)))))
+        .collect();
+
+    let mut broken: Vec<BrokenLink> = vec![];
+
+    for (path, src) in documents.iter() {
+        let mut lexer = Lexer::new(options);
+        let tokens = lexer.lex(src);
+
+        let mut located = vec![];
+        let map = SourceMap::new(src);
+        locate_tokens(&map, tokens, 0, map.normalized.len(), &mut located);
+
+        for (token, span) in located.into_iter() {
+            let t = token.as_ref().borrow();
+            if (t._type != "link" && t._type != "image") || !is_relative(t.href.as_str()) {
+                continue;
+            }
+
+            let href = t.href.as_str();
+            let (target, fragment) = match href.find('#') {
+                Some(idx) => (&href[..idx], Some(&href[idx + 1..])),
+                None      => (href, None)
+            };
+            let target = target.split('?').next().unwrap_or("");
+            let target = urlencoding::decode(target).map_or(target.to_string(), |t| t.into_owned());
+
+            let target_path = if target.is_empty() {
+                normalize_path(path)
+            } else {
+                resolve_path(path, target.as_str())
+            };
+
+            if !targets.contains_key(&target_path) {
+                targets.insert(target_path.clone(), lookup(target_path.as_str(), &options));
+            }
+
+            let problem = match &targets[&target_path] {
+                Target::Document(target_ids) => {
+                    let missing = fragment.map_or(false, |fragment| {
+                        let decoded = urlencoding::decode(fragment).map_or(fragment.to_string(), |f| f.into_owned());
+                        !fragment.is_empty() && !target_ids.contains(fragment) && !target_ids.contains(&decoded)
+                    });
+                    if missing { Some(Problem::MissingAnchor) } else { None }
+                }
+                Target::Missing   => Some(Problem::MissingFile),
+                Target::Unchecked => None
+            };
+
+            if problem.is_some() {
+                broken.push(BrokenLink {
+                    path: path.to_string(),
+                    href: href.to_string(),
+                    problem: problem.unwrap(),
+                    span
+                });
+            }
+        }
+    }
+    broken
+}
+
+// Links without a scheme, host or absolute path
+fn is_relative(href: &str) -> bool {
+    let scheme = href.find(':').map_or(false, |idx| !href[..idx].contains(|c| c == '/' || c == '#' || c == '?'));
+    !href.is_empty() && !scheme && !href.starts_with('/')
+}
+
+fn is_markdown(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    lower.ends_with(".md") || lower.ends_with(".markdown")
+}
+
+fn resolve_path(from: &str, target: &str) -> String {
+    let normalized = normalize_path(from);
+    let dir = match normalized.rfind('/') {
+        Some(idx) => &normalized[..idx + 1],
+        None      => ""
+    };
+    normalize_path(format!("{}{}", dir, target).as_str())
+}
+
+// Resolve `.` and `..` segments
+fn normalize_path(path: &str) -> String {
+    let mut segments: Vec<&str> = vec![];
+
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if !segments.is_empty() && *segments.last().unwrap() != ".." => { segments.pop(); }
+            _ => segments.push(segment)
+        }
+    }
+
+    let joined = segments.join("/");
+    if path.starts_with('/') { format!("/{}", joined) } else { joined }
+}
@@ -1,9 +1,78 @@
 #![allow(warnings, unused)]
 use std::rc::Rc;
 use std::cell::RefCell;
-use crate::tokenizer::Token;
+use crate::tokenizer::{Token, WikiLink};
+use crate::source_map::Span;
 
 pub type Callback = fn(token: &mut Rc<RefCell<Token>>);
+pub type WikiLinkResolver = fn(target: &str) -> WikiLink;
+pub type ContainerRenderer = fn(args: &str, body: &str) -> String;
+pub type DiagramRenderer = fn(lang: &str, source: &str) -> Option<String>;
+pub type BrokenLinkCallback = fn(label: &str, span: &Span) -> Option<(String, String)>;
+pub type UrlRewriter = fn(url: &str, kind: UrlKind, token: &Token) -> Option<String>;
+pub type ImageDimensions = fn(src: &str) -> Option<(u32, u32)>;
+pub type MangleRng = fn() -> f64;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum UrlKind {
+    // `[text](url)`, reference and wiki links
+    Link,
+    // `![alt](url)` and reference images
+    Image,
+    // `<url>`, GFM bare URLs and emails, issue and mention references
+    Autolink,
+    // `href` attribute in raw HTML
+    HtmlHref,
+    // `src` attribute in raw HTML
+    HtmlSrc
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum HtmlMode {
+    // Output as is
+    Allow,
+    // Output as text
+    Escape,
+    // Left out
+    Strip
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MangleStrategy {
+    // Emails are written as is
+    Off,
+    // Entities chosen from a hash of the address, stable across runs
+    Deterministic,
+    // Entities chosen by `mangle_rng`, deterministic when none is given
+    Random,
+    // Entity-encoded `mailto:`, text written backwards and turned around by CSS
+    Css
+}
+
+// Where a heading's permalink anchor goes
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum HeadingAnchors {
+    Off,
+    // Symbol link before the text
+    Before,
+    // Symbol link after the text
+    After,
+    // Text as the link, or After when the text holds a link already
+    Wrap
+}
+
+// Quotation marks used by smartypants
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SmartypantsLocale {
+    // ‘single’ and “double”
+    En,
+    // ‚single‘ and „double“
+    De,
+    // ‹ single › and « double », with narrow no-break spaces
+    Fr,
+    // 『single』 and 「double」
+    Ja
+}
 
 #[derive(Clone)]
 pub struct Options {
@@ -16,7 +85,9 @@ pub struct Options {
     pub lang_prefix: &'static str,
     pub mangle: bool,
     pub pedantic: bool,
+    #[deprecated(note = "use `sanitize_html`, the allowlist sanitizer, instead")]
     pub sanitize: bool,
+    #[deprecated(note = "use `sanitize_html` with `sanitize_tags`, `sanitize_attributes` and `sanitize_url_schemes` instead")]
     pub sanitizer: Option<fn(cap: &str)->String>,
     pub silent: bool,
     pub smart_lists: bool,
@@ -24,9 +95,102 @@ pub struct Options {
     pub is_highlight: bool,
     pub tokenizer: Option<&'static str>,
     pub walk_tokens: Option<Callback>,
-    pub xhtml: bool
+    pub xhtml: bool,
+    pub alerts: bool,
+    pub alert_types: &'static [&'static str],
+    pub definition_lists: bool,
+    pub attributes: bool,
+    pub emoji: bool,
+    pub emoji_image_url: &'static str,
+    pub emoji_aliases: &'static [(&'static str, &'static str)],
+    pub references: bool,
+    pub reference_repo: &'static str,
+    pub issue_url: &'static str,
+    pub commit_url: &'static str,
+    pub mention_url: &'static str,
+    pub wiki_links: bool,
+    pub wiki_link_resolver: Option<WikiLinkResolver>,
+    pub mark: bool,
+    pub superscript: bool,
+    pub subscript: bool,
+    pub inserted: bool,
+    pub abbreviations: bool,
+    pub table_extensions: bool,
+    pub containers: bool,
+    pub container_renderers: &'static [(&'static str, ContainerRenderer)],
+    pub diagrams: bool,
+    pub diagram_languages: &'static [&'static str],
+    pub diagram_renderer: Option<DiagramRenderer>,
+    pub smartypants_locale: SmartypantsLocale,
+    pub task_list_data: bool,
+    pub task_list_interactive: bool,
+    pub broken_link_callback: Option<BrokenLinkCallback>,
+    pub sanitize_html: bool,
+    pub sanitize_output: bool,
+    pub sanitize_tags: &'static [&'static str],
+    pub sanitize_attributes: &'static [(&'static str, &'static [&'static str])],
+    pub sanitize_url_schemes: &'static [(&'static str, &'static [&'static str])],
+    pub tagfilter: bool,
+    pub raw_html: HtmlMode,
+    pub html_comments: HtmlMode,
+    pub url_rewriter: Option<UrlRewriter>,
+    pub external_links: bool,
+    pub own_hosts: &'static [&'static str],
+    pub external_link_rel: &'static str,
+    pub external_link_target: &'static str,
+    pub external_link_class: &'static str,
+    pub mailto_link_class: &'static str,
+    pub image_loading: &'static str,
+    pub image_decoding: &'static str,
+    pub image_size_hints: bool,
+    pub image_figures: bool,
+    pub image_dimensions: Option<ImageDimensions>,
+    pub mangle_strategy: MangleStrategy,
+    pub mangle_rng: Option<MangleRng>,
+    pub heading_anchors: HeadingAnchors,
+    pub heading_anchor_levels: &'static [usize],
+    pub heading_anchor_symbol: &'static str,
+    pub heading_anchor_class: &'static str,
+    pub style_attributes: bool
 }
 
+pub const DEFAULT_ALERT_TYPES: &[&str] = &["note", "tip", "important", "warning", "caution"];
+pub const DEFAULT_DIAGRAM_LANGUAGES: &[&str] = &["mermaid", "plantuml", "dot"];
+pub const DEFAULT_HEADING_ANCHOR_LEVELS: &[usize] = &[1, 2, 3, 4, 5, 6];
+
+// Elements kept by the allowlist sanitizer: everything the renderer emits
+// plus common inline and block formatting
+pub const DEFAULT_SANITIZE_TAGS: &[&str] = &[
+    "a", "abbr", "b", "blockquote", "br", "caption", "code", "dd", "del", "details",
+    "div", "dl", "dt", "em", "figcaption", "figure", "h1", "h2", "h3", "h4", "h5",
+    "h6", "hr", "i", "img", "input", "ins", "kbd", "li", "mark", "ol", "p", "pre",
+    "q", "s", "samp", "small", "span", "strong", "sub", "summary", "sup", "table",
+    "tbody", "td", "tfoot", "th", "thead", "tr", "u", "ul", "var"
+];
+
+// Allowed attributes per tag, `*` applying to every allowed tag
+pub const DEFAULT_SANITIZE_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("*", &["id", "class", "title", "lang", "dir", "align"]),
+    ("a", &["href", "name"]),
+    ("img", &["src", "alt", "width", "height"]),
+    ("input", &["type", "checked", "disabled"]),
+    ("ol", &["start"]),
+    ("td", &["colspan", "rowspan"]),
+    ("th", &["colspan", "rowspan"]),
+    ("blockquote", &["cite"]),
+    ("q", &["cite"]),
+    ("del", &["cite"]),
+    ("ins", &["cite"]),
+    ("details", &["open"])
+];
+
+// URL schemes allowed per attribute; relative URLs are always allowed
+pub const DEFAULT_SANITIZE_URL_SCHEMES: &[(&str, &[&str])] = &[
+    ("href", &["http", "https", "mailto"]),
+    ("src", &["http", "https"]),
+    ("cite", &["http", "https"])
+];
+
 pub enum Default {
     BaseUrl,
     Breaks,
@@ -46,7 +210,63 @@ pub enum Default {
     Smartypants,
     Tokenizer,
     WalkTokens,
-    Xhtml
+    Xhtml,
+    Alerts,
+    AlertTypes,
+    DefinitionLists,
+    Attributes,
+    Emoji,
+    EmojiImageUrl,
+    EmojiAliases,
+    References,
+    ReferenceRepo,
+    IssueUrl,
+    CommitUrl,
+    MentionUrl,
+    WikiLinks,
+    WikiLinkResolver,
+    Mark,
+    Superscript,
+    Subscript,
+    Inserted,
+    Abbreviations,
+    TableExtensions,
+    Containers,
+    ContainerRenderers,
+    Diagrams,
+    DiagramLanguages,
+    DiagramRenderer,
+    SmartypantsLocale,
+    TaskListData,
+    TaskListInteractive,
+    BrokenLinkCallback,
+    SanitizeHtml,
+    SanitizeOutput,
+    SanitizeTags,
+    SanitizeAttributes,
+    SanitizeUrlSchemes,
+    Tagfilter,
+    RawHtml,
+    HtmlComments,
+    UrlRewriter,
+    ExternalLinks,
+    OwnHosts,
+    ExternalLinkRel,
+    ExternalLinkTarget,
+    ExternalLinkClass,
+    MailtoLinkClass,
+    ImageLoading,
+    ImageDecoding,
+    ImageSizeHints,
+    ImageFigures,
+    ImageDimensions,
+    MangleStrategy,
+    MangleRng,
+    HeadingAnchors,
+    HeadingAnchorLevels,
+    HeadingAnchorSymbol,
+    HeadingAnchorClass,
+    StyleAttributes
 }
 
 impl Options {
@@ -69,7 +289,63 @@ impl Options {
             tokenizer: None,
             is_highlight: false,
             xhtml: false,
-            walk_tokens: None
+            walk_tokens: None,
+            alerts: false,
+            alert_types: DEFAULT_ALERT_TYPES,
+            definition_lists: false,
+            attributes: false,
+            emoji: false,
+            emoji_image_url: "",
+            emoji_aliases: &[],
+            references: false,
+            reference_repo: "",
+            issue_url: "https://github.com/{repo}/issues/{number}",
+            commit_url: "https://github.com/{repo}/commit/{sha}",
+            mention_url: "https://github.com/{user}",
+            wiki_links: false,
+            wiki_link_resolver: None,
+            mark: false,
+            superscript: false,
+            subscript: false,
+            inserted: false,
+            abbreviations: false,
+            table_extensions: false,
+            containers: false,
+            container_renderers: &[],
+            diagrams: false,
+            diagram_languages: DEFAULT_DIAGRAM_LANGUAGES,
+            diagram_renderer: None,
+            smartypants_locale: SmartypantsLocale::En,
+            task_list_data: false,
+            task_list_interactive: false,
+            broken_link_callback: None,
+            sanitize_html: false,
+            sanitize_output: false,
+            sanitize_tags: DEFAULT_SANITIZE_TAGS,
+            sanitize_attributes: DEFAULT_SANITIZE_ATTRIBUTES,
+            sanitize_url_schemes: DEFAULT_SANITIZE_URL_SCHEMES,
+            tagfilter: true,
+            raw_html: HtmlMode::Allow,
+            html_comments: HtmlMode::Allow,
+            url_rewriter: None,
+            external_links: false,
+            own_hosts: &[],
+            external_link_rel: "nofollow noopener noreferrer",
+            external_link_target: "_blank",
+            external_link_class: "",
+            mailto_link_class: "",
+            image_loading: "",
+            image_decoding: "",
+            image_size_hints: false,
+            image_figures: false,
+            image_dimensions: None,
+            mangle_strategy: MangleStrategy::Deterministic,
+            mangle_rng: None,
+            heading_anchors: HeadingAnchors::Off,
+            heading_anchor_levels: DEFAULT_HEADING_ANCHOR_LEVELS,
+            heading_anchor_symbol: "#",
+            heading_anchor_class: "anchor",
+            style_attributes: false
         }
     }
 
@@ -81,6 +357,7 @@ impl Options {
         self = new_defaults;
     }
 
+    #[deprecated(note = "set `sanitize_html` instead")]
     pub fn enable_sanitize(&mut self) {
         self.sanitize = true;
     }
@@ -108,7 +385,63 @@ pub fn get_default_options() -> Options {
         is_highlight: false,
         tokenizer: None,
         walk_tokens: None,
-        xhtml: false
+        xhtml: false,
+        alerts: false,
+        alert_types: DEFAULT_ALERT_TYPES,
+        definition_lists: false,
+        attributes: false,
+        emoji: false,
+        emoji_image_url: "",
+        emoji_aliases: &[],
+        references: false,
+        reference_repo: "",
+        issue_url: "https://github.com/{repo}/issues/{number}",
+        commit_url: "https://github.com/{repo}/commit/{sha}",
+        mention_url: "https://github.com/{user}",
+        wiki_links: false,
+        wiki_link_resolver: None,
+        mark: false,
+        superscript: false,
+        subscript: false,
+        inserted: false,
+        abbreviations: false,
+        table_extensions: false,
+        containers: false,
+        container_renderers: &[],
+        diagrams: false,
+        diagram_languages: DEFAULT_DIAGRAM_LANGUAGES,
+        diagram_renderer: None,
+        smartypants_locale: SmartypantsLocale::En,
+        task_list_data: false,
+        task_list_interactive: false,
+        broken_link_callback: None,
+        sanitize_html: false,
+        sanitize_output: false,
+        sanitize_tags: DEFAULT_SANITIZE_TAGS,
+        sanitize_attributes: DEFAULT_SANITIZE_ATTRIBUTES,
+        sanitize_url_schemes: DEFAULT_SANITIZE_URL_SCHEMES,
+        tagfilter: true,
+        raw_html: HtmlMode::Allow,
+        html_comments: HtmlMode::Allow,
+        url_rewriter: None,
+        external_links: false,
+        own_hosts: &[],
+        external_link_rel: "nofollow noopener noreferrer",
+        external_link_target: "_blank",
+        external_link_class: "",
+        mailto_link_class: "",
+        image_loading: "",
+        image_decoding: "",
+        image_size_hints: false,
+        image_figures: false,
+        image_dimensions: None,
+        mangle_strategy: MangleStrategy::Deterministic,
+        mangle_rng: None,
+        heading_anchors: HeadingAnchors::Off,
+        heading_anchor_levels: DEFAULT_HEADING_ANCHOR_LEVELS,
+        heading_anchor_symbol: "#",
+        heading_anchor_class: "anchor",
+        style_attributes: false
     }
 }
 
@@ -131,7 +464,63 @@ pub fn get_base_options(gfm: bool, pedantic: bool, header_ids: bool, sanitize: b
         is_highlight: false,
         tokenizer: None,
         walk_tokens: None,
-        xhtml: false
+        xhtml: false,
+        alerts: false,
+        alert_types: DEFAULT_ALERT_TYPES,
+        definition_lists: false,
+        attributes: false,
+        emoji: false,
+        emoji_image_url: "",
+        emoji_aliases: &[],
+        references: false,
+        reference_repo: "",
+        issue_url: "https://github.com/{repo}/issues/{number}",
+        commit_url: "https://github.com/{repo}/commit/{sha}",
+        mention_url: "https://github.com/{user}",
+        wiki_links: false,
+        wiki_link_resolver: None,
+        mark: false,
+        superscript: false,
+        subscript: false,
+        inserted: false,
+        abbreviations: false,
+        table_extensions: false,
+        containers: false,
+        container_renderers: &[],
+        diagrams: false,
+        diagram_languages: DEFAULT_DIAGRAM_LANGUAGES,
+        diagram_renderer: None,
+        smartypants_locale: SmartypantsLocale::En,
+        task_list_data: false,
+        task_list_interactive: false,
+        broken_link_callback: None,
+        sanitize_html: false,
+        sanitize_output: false,
+        sanitize_tags: DEFAULT_SANITIZE_TAGS,
+        sanitize_attributes: DEFAULT_SANITIZE_ATTRIBUTES,
+        sanitize_url_schemes: DEFAULT_SANITIZE_URL_SCHEMES,
+        tagfilter: gfm,
+        raw_html: HtmlMode::Allow,
+        html_comments: HtmlMode::Allow,
+        url_rewriter: None,
+        external_links: false,
+        own_hosts: &[],
+        external_link_rel: "nofollow noopener noreferrer",
+        external_link_target: "_blank",
+        external_link_class: "",
+        mailto_link_class: "",
+        image_loading: "",
+        image_decoding: "",
+        image_size_hints: false,
+        image_figures: false,
+        image_dimensions: None,
+        mangle_strategy: MangleStrategy::Deterministic,
+        mangle_rng: None,
+        heading_anchors: HeadingAnchors::Off,
+        heading_anchor_levels: DEFAULT_HEADING_ANCHOR_LEVELS,
+        heading_anchor_symbol: "#",
+        heading_anchor_class: "anchor",
+        style_attributes: false
     }
 }
 
@@ -166,7 +555,63 @@ pub fn get_options(
         is_highlight: false,
         tokenizer: None,
         walk_tokens: None,
-        xhtml
+        xhtml,
+        alerts: false,
+        alert_types: DEFAULT_ALERT_TYPES,
+        definition_lists: false,
+        attributes: false,
+        emoji: false,
+        emoji_image_url: "",
+        emoji_aliases: &[],
+        references: false,
+        reference_repo: "",
+        issue_url: "https://github.com/{repo}/issues/{number}",
+        commit_url: "https://github.com/{repo}/commit/{sha}",
+        mention_url: "https://github.com/{user}",
+        wiki_links: false,
+        wiki_link_resolver: None,
+        mark: false,
+        superscript: false,
+        subscript: false,
+        inserted: false,
+        abbreviations: false,
+        table_extensions: false,
+        containers: false,
+        container_renderers: &[],
+        diagrams: false,
+        diagram_languages: DEFAULT_DIAGRAM_LANGUAGES,
+        diagram_renderer: None,
+        smartypants_locale: SmartypantsLocale::En,
+        task_list_data: false,
+        task_list_interactive: false,
+        broken_link_callback: None,
+        sanitize_html: false,
+        sanitize_output: false,
+        sanitize_tags: DEFAULT_SANITIZE_TAGS,
+        sanitize_attributes: DEFAULT_SANITIZE_ATTRIBUTES,
+        sanitize_url_schemes: DEFAULT_SANITIZE_URL_SCHEMES,
+        tagfilter: gfm,
+        raw_html: HtmlMode::Allow,
+        html_comments: HtmlMode::Allow,
+        url_rewriter: None,
+        external_links: false,
+        own_hosts: &[],
+        external_link_rel: "nofollow noopener noreferrer",
+        external_link_target: "_blank",
+        external_link_class: "",
+        mailto_link_class: "",
+        image_loading: "",
+        image_decoding: "",
+        image_size_hints: false,
+        image_figures: false,
+        image_dimensions: None,
+        mangle_strategy: MangleStrategy::Deterministic,
+        mangle_rng: None,
+        heading_anchors: HeadingAnchors::Off,
+        heading_anchor_levels: DEFAULT_HEADING_ANCHOR_LEVELS,
+        heading_anchor_symbol: "#",
+        heading_anchor_class: "anchor",
+        style_attributes: false
     }
 }
 
@@ -1,13 +1,14 @@
 // Helpers
 #![allow(warnings, unused)]
 use std::fs;
-use std::cmp::max;
+use std::cmp::{max, min};
 use std::io::Write;
 use std::fmt::format;
 use std::borrow::{Cow};
 use std::fs::OpenOptions;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
+use url::Url;
 use urlencoding::{encode, decode};
 use fancy_regex::{Captures, Regex};
 use serde::{Serialize, Deserialize};
@@ -121,6 +122,55 @@ pub fn unescape(html: &str) -> String {
     }).to_string();
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LinkKind {
+    Internal,
+    External,
+    Mailto
+}
+
+// Classify a link target: relative URLs and URLs on one of `own_hosts` are
+// internal; a `*.example.com` entry matches every subdomain of example.com
+pub fn classify_link(href: &str, own_hosts: &[&str]) -> LinkKind {
+    let parsed = if href.starts_with("//") {
+        Url::parse(format!("https:{}", href).as_str())
+    } else {
+        Url::parse(href)
+    };
+
+    if parsed.is_err() {
+        return LinkKind::Internal;
+    }
+
+    let url = parsed.unwrap();
+    if url.scheme() == "mailto" {
+        return LinkKind::Mailto;
+    }
+
+    let host = match url.host_str() {
+        Some(host) => host.to_lowercase(),
+        None       => return LinkKind::Internal
+    };
+
+    let own = own_hosts.iter().any(|own| {
+        let own = own.to_lowercase();
+        match own.strip_prefix("*.") {
+            Some(domain) => host.ends_with(format!(".{}", domain).as_str()),
+            None         => host == own
+        }
+    });
+
+    if own { LinkKind::Internal } else { LinkKind::External }
+}
+
+// GFM tagfilter: escape the `<` of tags that change how the rest of the page
+// is parsed, like `<script>` or `<textarea>`
+pub fn filter_disallowed_tags(html: &str) -> String {
+    regx_helper(RegexHelper::TagFilter)
+        .replace_all(html, "&lt;$1")
+        .to_string()
+}
+
 pub fn clean_url(sanitize: bool, base: &str, href: &str) -> Option<String> {
 
     let mut _href = String::from(href);
@@ -175,6 +225,86 @@ pub fn clean_url(sanitize: bool, base: &str, href: &str) -> Option<String> {
     Some(_href)
 }
 
+// Attributes the renderer writes itself, after cleaning them
+const RESERVED_ATTRIBUTES: &[&str] = &["href", "src", "alt", "title"];
+
+/**
+ * parse an attribute list such as `#id .cls key=value`
+ * returns None when the list contains anything else
+ */
+pub fn parse_attributes(attrs_str: &str, allow_style: bool) -> Option<Vec<(String, String)>> {
+    if !regx_helper(RegexHelper::AttributeList).is_match(attrs_str) {
+        return None;
+    }
+
+    let mut id = String::from("");
+    let mut classes: Vec<String> = vec![];
+    let mut attrs: Vec<(String, String)> = vec![];
+
+    for caps in regx_helper(RegexHelper::Attribute).captures_iter(attrs_str) {
+        if let Some(m) = caps.get(1) {
+            id = m.as_str().to_string();
+        } else if let Some(m) = caps.get(2) {
+            classes.push(m.as_str().to_string());
+        } else {
+            let key = caps.get(3).map_or("", |m| m.as_str()).to_lowercase();
+            let value = caps.get(4)
+                .or(caps.get(5))
+                .or(caps.get(6))
+                .map_or("", |m| m.as_str())
+                .to_string();
+
+            // Never pass through event handlers, nor override what the
+            // renderer cleans
+            if key.starts_with("on") || RESERVED_ATTRIBUTES.contains(&key.as_str()) {
+                continue;
+            }
+            if key == "style" && !allow_style {
+                continue;
+            }
+
+            if key == "id" {
+                id = value;
+            } else if key == "class" {
+                classes.push(value);
+            } else {
+                attrs.retain(|(k, _)| *k != key);
+                attrs.push((key, value));
+            }
+        }
+    }
+
+    if !classes.is_empty() {
+        attrs.insert(0, ("class".to_string(), classes.join(" ")));
+    }
+    if !id.is_empty() {
+        attrs.insert(0, ("id".to_string(), id));
+    }
+
+    Some(attrs)
+}
+
+/**
+ * split a trailing `{...}` attribute list from text
+ */
+pub fn strip_trailing_attributes(text: &str, allow_style: bool) -> Option<(String, Vec<(String, String)>)> {
+    let caps = regx_helper(RegexHelper::TrailingAttributes).captures(text)?;
+    let attrs = parse_attributes(caps.get(1).map_or("", |m| m.as_str()), allow_style)?;
+    let start = caps.get(0).unwrap().start();
+
+    Some((text[..start].to_string(), attrs))
+}
+
+/**
+ * split a leading `{...}` attribute list from src, returning its raw length
+ */
+pub fn take_leading_attributes(src: &str, allow_style: bool) -> Option<(usize, Vec<(String, String)>)> {
+    let caps = regx_helper(RegexHelper::LeadingAttributes).captures(src)?;
+    let attrs = parse_attributes(caps.get(1).map_or("", |m| m.as_str()), allow_style)?;
+
+    Some((caps.get(0).unwrap().end(), attrs))
+}
+
 pub fn split_cells(table_row: &str, count: Option<usize>) -> Vec<String> {
 
     let row = get_row(table_row);
@@ -215,6 +345,59 @@ pub fn split_cells(table_row: &str, count: Option<usize>) -> Vec<String> {
     cells
 }
 
+/**
+ * Like split_cells, but an empty cell written as `||` (no space between the
+ * pipes) is merged into the cell before it. Returns each cell with its
+ * colspan; with a `count` the colspans are fitted to add up to it.
+ */
+pub fn split_cells_with_spans(table_row: &str, count: Option<usize>) -> Vec<(String, usize)> {
+    let row = get_row(table_row);
+
+    let mut pieces: Vec<&str> = regx_helper(RegexHelper::TableCell).split(row.as_str()).collect();
+
+    // First/last cell in a row cannot be empty if it has no leading/trailing pipe
+    if pieces.first().map_or(false, |piece| piece.trim().is_empty()) {
+        pieces.remove(0);
+    }
+    if pieces.last().map_or(false, |piece| piece.trim().is_empty()) {
+        pieces.pop();
+    }
+
+    let mut cells: Vec<(String, usize)> = vec![];
+    for piece in pieces {
+        if piece.is_empty() && !cells.is_empty() {
+            cells.last_mut().unwrap().1 += 1;
+        } else {
+            let text = regx_helper(RegexHelper::TrailingWhitespace)
+                .replace_all(piece.trim(), "|")
+                .to_string();
+            cells.push((text, 1));
+        }
+    }
+
+    if count.is_none() {
+        return cells;
+    }
+
+    let count = count.unwrap();
+    let mut fitted: Vec<(String, usize)> = vec![];
+    let mut total = 0;
+    for (text, span) in cells {
+        if total >= count {
+            break;
+        }
+        let span = min(span, count - total);
+        total += span;
+        fitted.push((text, span));
+    }
+
+    while total < count {
+        fitted.push(("".to_string(), 1));
+        total += 1;
+    }
+    fitted
+}
+
 pub fn get_row(a: &str) -> String {
     let row = regx_helper(RegexHelper::Row)
         .replace_all(a, |cap: &regex::Captures| {
@@ -336,20 +519,18 @@ pub fn find_closing_bracket(_str: &str, b: &str) -> i32 {
         return -1;
     }
 
-    let l = _str.len();
+    // Brackets are ASCII, so scanning bytes gives byte offsets to slice with
+    let bytes = _str.as_bytes();
+    let (open, close) = (b.as_bytes()[0], b.as_bytes()[1]);
     let mut level: i32 = 0;
     let mut i: usize = 0;
 
-    while i < l {
-        if _str.chars().nth(i).unwrap().to_string() == "\\" {
+    while i < bytes.len() {
+        if bytes[i] == b'\\' {
             i += 1;
-        } else if _str.chars().nth(i).unwrap().to_string() ==
-            b.chars().nth(0).unwrap().to_string()
-        {
+        } else if bytes[i] == open {
             level += 1;
-        } else if _str.chars().nth(i).unwrap().to_string() ==
-            b.chars().nth(1).unwrap().to_string()
-        {
+        } else if bytes[i] == close {
             level -= 1;
             if level < 0 {
                 return i as i32;
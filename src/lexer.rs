@@ -1,15 +1,15 @@
 #![allow(warnings, unused)]
-use rand::Rng;
 use std::rc::Rc;
 use regex::Regex;
 use std::cell::RefCell;
 
-use crate::helpers::repeat_string;
-use crate::defaults::{Options};
+use crate::helpers::{escape, repeat_string};
+use crate::defaults::{MangleStrategy, Options, SmartypantsLocale};
 use crate::regex::{RegexHelper, regx_helper};
+use crate::source_map::{SourceMap, Span};
 use crate::rules::{MDInline};
 use crate::token;
-use crate::tokenizer::{ITokenizer, Link, slice, Token, Tokenizer};
+use crate::tokenizer::{Abbreviation, ITokenizer, Link, LinkDefinition, LinkReference, slice, Token, Tokenizer};
 
 pub struct State {
     pub in_link: bool,
@@ -20,10 +20,17 @@ pub struct State {
 pub struct Lexer {
     pub state: State,
     pub links: Vec<Link>,
+    pub link_definitions: Vec<LinkDefinition>,
+    pub link_references: Vec<LinkReference>,
+    pub abbreviations: Vec<Abbreviation>,
     pub options: Options,
     pub tokenizer: Tokenizer,
     pub tokens: Vec<Rc<RefCell<Token>>>,
     pub inline_queue: Vec<InlineToken>,
+    // Source positions for the broken link callback, and where the last
+    // recorded reference ended
+    source_map: Option<SourceMap>,
+    reference_cursor: usize
 }
 
 
@@ -50,10 +57,15 @@ impl Lexer {
     pub fn new(options: Options) -> Self  {
         Self {
             links: vec![],
+            link_definitions: vec![],
+            link_references: vec![],
+            abbreviations: vec![],
             tokens: vec![],
             options,
             tokenizer: Tokenizer::new(Some(options)),
             inline_queue: vec![],
+            source_map: None,
+            reference_cursor: 0,
             state: State {
                 in_link: false,
                 in_raw_block: false,
@@ -66,6 +78,69 @@ impl Lexer {
         self.links.clone()
     }
 
+    pub fn get_abbreviations(&mut self) -> Vec<Abbreviation> {
+        self.abbreviations.clone()
+    }
+
+    fn record_link_definition(&mut self, token: &Token) {
+        let label = token.raw.trim_start()
+            .trim_start_matches('[')
+            .split("]:")
+            .next()
+            .unwrap_or("")
+            .to_string();
+
+        self.link_definitions.push(LinkDefinition {
+            raw: token.raw.to_string(),
+            label,
+            href: token.href.to_string(),
+            title: token.title.to_string()
+        });
+    }
+
+    // Span of a recorded reference, searched for after the previous one
+    fn reference_span(&mut self, raw: &str) -> Span {
+        let map = self.source_map.as_ref().unwrap();
+        let (pos, len) = map.locate(raw, self.reference_cursor, map.normalized.len(), false)
+            .unwrap_or((self.reference_cursor, 0));
+
+        self.reference_cursor = pos + len;
+        map.span(pos, len)
+    }
+
+    // Ask the broken link callback about a label without a definition; its
+    // answer is kept as if the document had defined the label
+    fn resolve_broken_link(&mut self, label: &str, span: &Span) -> bool {
+        match (self.options.broken_link_callback.unwrap())(label, span) {
+            Some((href, title)) => {
+                let tag = regx_helper(RegexHelper::Spaces).replace_all(label, " ").to_lowercase();
+                self.links.push(Link { href, title, tag });
+                true
+            }
+            None => false
+        }
+    }
+
+    // Wraps whole-word occurrences of the collected abbreviations in text
+    // tokens, leaving code, raw html and link text alone
+    fn abbreviate_tokens(&self, tokens: &mut Vec<Rc<RefCell<Token>>>) {
+        if !self.options.abbreviations || self.abbreviations.is_empty() {
+            return;
+        }
+
+        let mut abbrs: Vec<String> = self.abbreviations.iter()
+            .map(|a| escape(a.abbr.as_str(), false))
+            .collect();
+        abbrs.sort_by(|a, b| b.len().cmp(&a.len()));
+
+        let pattern = abbrs.iter()
+            .map(|a| regex::escape(a.as_str()))
+            .collect::<Vec<String>>()
+            .join("|");
+
+        abbreviate(tokens, &Regex::new(pattern.as_str()).unwrap(), &self.abbreviations);
+    }
+
     pub fn _lex(src: &str, options: Options) -> Lexer  {
         let mut lexer = Lexer::new(options);
         lexer.lex(src);
@@ -105,6 +180,7 @@ impl Lexer {
                     rows: token_rc.rows.to_owned(),
                     header: token_rc.header.to_owned(),
                     code_block_style: token_rc.code_block_style.clone(),
+                    attrs: token_rc.attrs.clone(),
                 }
 
             })
@@ -161,7 +237,8 @@ impl Lexer {
             align: token_rc.align.clone(),
             rows: vec![],
             header: vec![],
-            code_block_style: token_rc.code_block_style.clone()
+            code_block_style: token_rc.code_block_style.clone(),
+            attrs: token_rc.attrs.clone()
         };
 
         {
@@ -237,7 +314,8 @@ impl Lexer {
             align: token_ac.align.clone(),
             rows: vec![],
             header: vec![],
-            code_block_style: token_ac.code_block_style.clone()
+            code_block_style: token_ac.code_block_style.clone(),
+            attrs: token_ac.attrs.clone()
         };
 
         {
@@ -294,12 +372,19 @@ impl ILexer for Lexer {
             self.inline_tokens(next.src.as_str(), i_tokens);
         }
 
+        self.abbreviate_tokens(&mut tokens);
+
         // // println!("Tokens: {:#?}", tokens);
 
         self.tokens.append(&mut tokens);
     }
 
     fn lex<'a>(&mut self, src: &str) -> &mut Vec<Rc<RefCell<Token>>> {
+        if self.options.broken_link_callback.is_some() {
+            self.source_map = Some(SourceMap::new(src));
+            self.reference_cursor = 0;
+        }
+
         let mut new_src = regx_helper(RegexHelper::LexerPreSpaces).replace_all(src, "\n").to_string();
         new_src = regx_helper(RegexHelper::LexerPreTabs).replace_all(new_src.as_str(), "    ").to_string();
 
@@ -313,6 +398,8 @@ impl ILexer for Lexer {
             self.inline_tokens(next.src.as_str(), i_tokens);
         }
 
+        self.abbreviate_tokens(&mut tokens);
+
         // // println!("Tokens: {:#?}", tokens);
 
         self.tokens.append(&mut tokens);
@@ -334,6 +421,8 @@ impl ILexer for Lexer {
             self.inline_tokens(next.src.as_str(), i_tokens);
         }
 
+        self.abbreviate_tokens(&mut tokens);
+
         // // println!("Tokens: {:#?}", tokens);
 
         self.tokens.append(&mut tokens);
@@ -349,6 +438,9 @@ impl ILexer for Lexer {
 
     fn lex_inline(&mut self, src: &str, options: Options) -> Vec<Rc<RefCell<Token>>> {
         let mut lexer = Lexer::new(options);
+        if options.broken_link_callback.is_some() {
+            lexer.source_map = Some(SourceMap::new(src));
+        }
 
         let mut inline_tokens = vec![];
         lexer.inline_tokens(src, &mut inline_tokens);
@@ -457,6 +549,28 @@ impl ILexer for Lexer {
                 continue;
             }
 
+            // container
+            token = self.tokenizer.container(_src.as_str());
+            if token.is_some() {
+
+                let mut container_token = token.unwrap();
+
+                let mut block_tokens: Vec<Rc<RefCell<Token>>> = vec![];
+                self.block_tokens(container_token.text.as_str(), &mut block_tokens);
+
+                container_token.tokens.append(&mut block_tokens);
+
+                let _token: Rc<RefCell<Token>> = Rc::new(RefCell::new(
+                    container_token
+                ));
+
+                let idx = _token.as_ref().borrow().raw.len();
+                _src = String::from(&_src[idx..]);
+
+                tokens.push(_token);
+                continue;
+            }
+
             // heading
             token = self.tokenizer.heading(_src.as_str());
             if token.is_some() {
@@ -599,6 +713,22 @@ impl ILexer for Lexer {
             }
 
 
+            // abbreviation definition
+            token = self.tokenizer.abbreviation(_src.as_str());
+            if token.is_some() {
+                let abbr_token = token.unwrap();
+
+                if !self.abbreviations.iter().any(|a| a.abbr == abbr_token.tag) {
+                    self.abbreviations.push(Abbreviation {
+                        abbr: abbr_token.tag.to_string(),
+                        title: abbr_token.title.to_string()
+                    });
+                }
+
+                _src = String::from(&_src[abbr_token.raw.len()..]);
+                continue;
+            }
+
             // def
             token = self.tokenizer.def(_src.as_str());
             if token.is_some() {
@@ -627,20 +757,28 @@ impl ILexer for Lexer {
 
                         let q_idx = self.inline_queue.len() - 1;
                         self.inline_queue[q_idx].src = _last_token.text.to_string();
-                    } else if link_idx.is_none()  {
+                    } else {
+                        self.record_link_definition(&_token.as_ref().borrow());
+
+                        if link_idx.is_none() {
+                            self.links.push(Link {
+                                href:  _token.as_ref().borrow().href.to_string(),
+                                title:  _token.as_ref().borrow().title.to_string(),
+                                tag:  _token.as_ref().borrow().tag.to_string()
+                            });
+                        }
+                    }
+
+                } else {
+                    self.record_link_definition(&_token.as_ref().borrow());
+
+                    if link_idx.is_none() {
                         self.links.push(Link {
                             href:  _token.as_ref().borrow().href.to_string(),
                             title:  _token.as_ref().borrow().title.to_string(),
                             tag:  _token.as_ref().borrow().tag.to_string()
                         });
                     }
-
-                } else if link_idx.is_none() {
-                    self.links.push(Link {
-                        href:  _token.as_ref().borrow().href.to_string(),
-                        title:  _token.as_ref().borrow().title.to_string(),
-                        tag:  _token.as_ref().borrow().tag.to_string()
-                    });
                 }
 
                 let idx = _token.as_ref().borrow().raw.len();
@@ -662,7 +800,7 @@ impl ILexer for Lexer {
                 ));
 
                 {
-                    let token_rc = table_token.as_ref().borrow_mut();
+                    let mut token_rc = table_token.as_ref().borrow_mut();
 
                     let mut l = token_rc.header.len();
                     for j in 0..l {
@@ -692,6 +830,13 @@ impl ILexer for Lexer {
                         }
                     }
 
+                    // caption of an extended table
+                    if !token_rc.text.is_empty() {
+                        let mut caption_tokens = vec![];
+                        self.inline_tokens(token_rc.text.as_str(), &mut caption_tokens);
+                        token_rc.tokens.append(&mut caption_tokens);
+                    }
+
                     let idx = token_rc.raw.len();
                     _src = String::from(&_src[idx..]);
                 }
@@ -719,6 +864,38 @@ impl ILexer for Lexer {
                 continue;
             }
 
+            // definition list
+            token = self.tokenizer.def_list(_src.as_str());
+            if token.is_some() {
+                let _token: Rc<RefCell<Token>> = Rc::new(RefCell::new(
+                    token.unwrap()
+                ));
+
+                let l = _token.as_ref().borrow().items.len();
+                for i in 0..l {
+                    let item = Rc::clone(&_token.as_ref().borrow().items[i]);
+                    let item_type = item.as_ref().borrow()._type;
+
+                    if item_type == "dt" {
+                        self.inline(item.as_ref().borrow().text.as_str(), Rc::clone(&item));
+                    } else {
+                        // Definitions hold block content, rendered tight unless loose
+                        self.state.top = false;
+
+                        let mut block_tokens: Vec<Rc<RefCell<Token>>> = vec![];
+                        self.block_tokens(item.as_ref().borrow().text.as_str(), &mut block_tokens);
+
+                        item.as_ref().borrow_mut().tokens = block_tokens;
+                    }
+                }
+
+                let idx = _token.as_ref().borrow().raw.len();
+                _src = String::from(&_src[idx..]);
+
+                tokens.push(_token);
+                continue;
+            }
+
             // top-level paragraph
             // prevent paragraph consuming extensions by clipping 'src' to extension start
             cut_src = _src.clone();
@@ -841,6 +1018,7 @@ impl ILexer for Lexer {
         let mut _match: Vec<&str>;
         let mut token: Option<Token>;
         let mut _keep_prev_char: bool = false;
+        let mut broken_ref_end: usize = usize::MAX;
 
         // Mask out reflinks
         if self.links.len() > 0 {
@@ -996,8 +1174,25 @@ impl ILexer for Lexer {
             }
 
 
+            // wiki link
+            if !self.state.in_link {
+                token = self.tokenizer.wiki_link(_src.as_str());
+                if token.is_some() {
+                    let wiki_link_token = Rc::new(RefCell::new(token.unwrap()));
+                    let idx = wiki_link_token.as_ref().borrow().raw.len();
+                    _src = String::from(&_src[idx..]);
+
+                    tokens.push(wiki_link_token);
+                    continue;
+                }
+            }
+
+
             // link
-            token = self.tokenizer.link(_src.as_str());
+            token = self.tokenizer.sized_image(_src.as_str());
+            if token.is_none() {
+                token = self.tokenizer.link(_src.as_str());
+            }
             if token.is_some() {
                 // println!("Inside Link");
                 let link_token = Rc::new(RefCell::new(token.unwrap()));
@@ -1023,6 +1218,31 @@ impl ILexer for Lexer {
             token = self.tokenizer.ref_link(_src.as_str(), &self.links);
             if token.is_some() {
 
+                if !self.state.in_link {
+                    let defined = token.as_ref().unwrap()._type != "text";
+
+                    // An undefined `[text][label]` leaves its brackets to be lexed
+                    // again; don't report the inner `[label]` a second time
+                    if let Some((raw, label)) = self.tokenizer.ref_link_label(_src.as_str()) {
+                        if defined || _src.len() <= broken_ref_end {
+                            let mut resolved = false;
+                            if self.source_map.is_some() {
+                                let span = self.reference_span(raw.as_str());
+                                resolved = !defined && self.resolve_broken_link(label.as_str(), &span);
+                            }
+
+                            if !defined && !resolved {
+                                broken_ref_end = _src.len() - raw.len();
+                            }
+                            self.link_references.push(LinkReference { raw, label, defined });
+
+                            if resolved {
+                                token = self.tokenizer.ref_link(_src.as_str(), &self.links);
+                            }
+                        }
+                    }
+                }
+
                 let reflink_token = Rc::new(RefCell::new(token.unwrap()));
                 let idx = reflink_token.as_ref().borrow().raw.len();
                 _src = String::from(&_src[idx..]);
@@ -1121,6 +1341,23 @@ impl ILexer for Lexer {
             }
 
 
+            // mark, ins, sup & sub
+            token = self.tokenizer.em_extended(_src.as_str(), _masked_src.as_str(), prev_char.as_str());
+            if token.is_some() {
+                let em_extended_token = Rc::new(RefCell::new(token.unwrap()));
+                let idx = em_extended_token.as_ref().borrow().raw.len();
+
+                let mut il_tokens = vec![];
+                self.inline_tokens(em_extended_token.as_ref().borrow().text.as_str(), &mut il_tokens);
+                em_extended_token.as_ref().borrow_mut().tokens.append(&mut il_tokens);
+
+                _src = String::from(&_src[idx..]);
+
+                tokens.push(em_extended_token);
+                continue;
+            }
+
+
             // autolink
             token = self.tokenizer.autolink(_src.as_str(), mangle);
             if token.is_some() {
@@ -1147,16 +1384,49 @@ impl ILexer for Lexer {
             }
 
 
+            // issue, commit and mention references
+            if !self.state.in_link && !self.state.in_raw_block {
+                token = self.tokenizer.reference(_src.as_str(), &src[..src.len() - _src.len()]);
+                if token.is_some() {
+                    let reference_token = Rc::new(RefCell::new(token.unwrap()));
+                    let idx = reference_token.as_ref().borrow().raw.len();
+                    _src = String::from(&_src[idx..]);
+
+                    tokens.push(reference_token);
+                    continue;
+                }
+            }
+
+
+            // emoji
+            if !self.state.in_raw_block {
+                token = self.tokenizer.emoji(_src.as_str());
+                if token.is_some() {
+                    let emoji_token = Rc::new(RefCell::new(token.unwrap()));
+                    let idx = emoji_token.as_ref().borrow().raw.len();
+                    _src = String::from(&_src[idx..]);
+
+                    tokens.push(emoji_token);
+                    continue;
+                }
+            }
+
+
             // text
             // prevent inlineText consuming extensions by clipping 'src' to extension start
             _cut_src = _src.clone();
             if self.options.extensions.is_some() {
                 // todo!("Implement logic to avoid clipping src");
             }
+            if !self.state.in_raw_block {
+                if let Some(start_index) = self.tokenizer.inline_start(&_src) {
+                    _cut_src = String::from(&_src[..start_index]);
+                }
+            }
 
             // Inline Text
             let in_raw_block = self.state.in_raw_block.clone();
-            token = self.tokenizer.inline_text(_cut_src.as_str(), in_raw_block, smartypants);
+            token = self.tokenizer.inline_text(_cut_src.as_str(), in_raw_block, smartypants_locale);
             if token.is_some() {
                 // println!("Entered Inline Text");
                 let inline_text_token = Rc::new(RefCell::new(token.unwrap()));
@@ -1224,6 +1494,99 @@ impl ILexer for Lexer {
 }
 
 
+fn abbreviate(tokens: &mut Vec<Rc<RefCell<Token>>>, abbr_re: &Regex, abbreviations: &Vec<Abbreviation>) {
+    let mut out: Vec<Rc<RefCell<Token>>> = vec![];
+    let mut in_raw_block = false;
+
+    for token in tokens.drain(..) {
+        let _type = token.as_ref().borrow()._type;
+
+        match _type {
+            "html" => { in_raw_block = token.as_ref().borrow().in_raw_block; }
+            "link" | "wikilink" | "image" | "codespan" | "code" => {}
+            "text" if token.as_ref().borrow().tokens.is_empty() => {
+                if !in_raw_block {
+                    let text = token.as_ref().borrow().text.to_string();
+                    out.append(&mut split_abbreviations(text.as_str(), abbr_re, abbreviations));
+                    continue;
+                }
+            }
+            _ => {
+                let mut t = token.as_ref().borrow_mut();
+                abbreviate(&mut t.tokens, abbr_re, abbreviations);
+                abbreviate(&mut t.items, abbr_re, abbreviations);
+                abbreviate(&mut t.header, abbr_re, abbreviations);
+                for row in t.rows.iter_mut() {
+                    abbreviate(row, abbr_re, abbreviations);
+                }
+            }
+        }
+        out.push(token);
+    }
+
+    *tokens = out;
+}
+
+fn split_abbreviations(text: &str, abbr_re: &Regex, abbreviations: &Vec<Abbreviation>) -> Vec<Rc<RefCell<Token>>> {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut parts = vec![];
+    let mut last = 0;
+
+    for m in abbr_re.find_iter(text) {
+        let before = text[..m.start()].chars().last();
+        let after = text[m.end()..].chars().next();
+
+        if before.map_or(false, |c| is_word_char(c) || c == '&') || after.map_or(false, is_word_char) {
+            continue;
+        }
+
+        let abbreviation = abbreviations.iter()
+            .find(|a| escape(a.abbr.as_str(), false) == m.as_str())
+            .unwrap();
+
+        if m.start() > last {
+            parts.push(new_text_token("text", &text[last..m.start()], ""));
+        }
+        parts.push(new_text_token("abbr", m.as_str(), abbreviation.title.as_str()));
+        last = m.end();
+    }
+
+    if last < text.len() {
+        parts.push(new_text_token("text", &text[last..], ""));
+    }
+    parts
+}
+
+fn new_text_token(_type: &'static str, text: &str, title: &str) -> Rc<RefCell<Token>> {
+    Rc::new(RefCell::new(Token {
+        _type,
+        raw: text.to_string(),
+        href: "".to_string(),
+        title: title.to_string(),
+        text: text.to_string(),
+        tokens: vec![],
+        tag: "".to_string(),
+        ordered: false,
+        start: 0,
+        lang: "".to_string(),
+        loose: false,
+        items: vec![],
+        depth: 0,
+        escaped: false,
+        pre: false,
+        task: false,
+        checked: false,
+        in_link: false,
+        in_raw_block: false,
+        links: vec![],
+        align: vec![],
+        rows: vec![],
+        header: vec![],
+        code_block_style: "".to_string(),
+        attrs: vec![]
+    }))
+}
+
 pub fn regx(regex: &str) -> Regex {
     return Regex::new(regex).unwrap();
 }
@@ -1232,47 +1595,137 @@ pub fn regx(regex: &str) -> Regex {
  * smartypants text replacement
  */
 pub fn smartypants(text: &str) -> String {
+    smartypants_locale(text, SmartypantsLocale::En)
+}
+
+// Placeholders keep apostrophes and quote kinds apart until the locale's
+// marks are substituted at the end
+const APOSTROPHE: char = '\u{E000}';
+const OPEN_SINGLE: char = '\u{E001}';
+const CLOSE_SINGLE: char = '\u{E002}';
+const OPEN_DOUBLE: char = '\u{E003}';
+const CLOSE_DOUBLE: char = '\u{E004}';
+
+/**
+ * smartypants text replacement using the quotation marks of `locale`
+ */
+pub fn smartypants_locale(text: &str, locale: SmartypantsLocale) -> String {
     let mut ret_text = text
         // em-dashes
         .replace("---", "\u{2014}")
         // en-dashes
-        .replace("--", "\u{2013}");
+        .replace("--", "\u{2013}")
+        // plus-minus
+        .replace("+-", "\u{00b1}");
+
+    // (c), (r), (tm)
+    ret_text = regx_helper(RegexHelper::SmartySymbol).replace_all(ret_text.as_str(), |caps: &regex::Captures| {
+        match caps[1].to_lowercase().as_str() {
+            "c" => "\u{00a9}",
+            "r" => "\u{00ae}",
+            _   => "\u{2122}"
+        }.to_string()
+    }).to_string();
+
+    // vulgar fractions, run twice as adjacent matches share a separator
+    for _ in 0..2 {
+        ret_text = regx_helper(RegexHelper::SmartyFraction).replace_all(ret_text.as_str(), |caps: &regex::Captures| {
+            let fraction = match &caps[2] {
+                "1/2" => "\u{00bd}",
+                "1/4" => "\u{00bc}",
+                _     => "\u{00be}"
+            };
+            format!("{}{}{}", &caps[1], fraction, &caps[3])
+        }).to_string();
+    }
+
+    // apostrophes: elisions ('tis, '90s), 'n' and inside words
+    ret_text = regx_helper(RegexHelper::SmartyElision).replace_all(ret_text.as_str(), format!("${{1}}{}${{2}}", APOSTROPHE).as_str()).to_string();
+    ret_text = regx_helper(RegexHelper::SmartyRockNRoll).replace_all(ret_text.as_str(), format!("${{1}}{}n{}${{2}}", APOSTROPHE, APOSTROPHE).as_str()).to_string();
+    for _ in 0..2 {
+        ret_text = regx_helper(RegexHelper::SmartyIntraword).replace_all(ret_text.as_str(), format!("${{1}}{}${{2}}", APOSTROPHE).as_str()).to_string();
+    }
 
     // opening singles
-    ret_text = regx_helper(RegexHelper::OpenSingles).replace_all(ret_text.as_str(), "${1}\u{2018}").to_string();
-    // closing singles & apostrophes
-    ret_text = ret_text.replace("'", "\u{2019}");
+    ret_text = regx_helper(RegexHelper::OpenSingles).replace_all(ret_text.as_str(), format!("${{1}}{}", OPEN_SINGLE).as_str()).to_string();
+    // closing singles
+    ret_text = ret_text.replace("'", CLOSE_SINGLE.to_string().as_str());
     // opening doubles
-    ret_text = regx_helper(RegexHelper::OpenDoubles).replace_all(ret_text.as_str(), "${1}\u{201c}").to_string();
+    ret_text = regx_helper(RegexHelper::SmartyOpenDoubles).replace_all(ret_text.as_str(), format!("${{1}}{}", OPEN_DOUBLE).as_str()).to_string();
     // closing doubles
-    ret_text = ret_text.replace(r#"""#, "\u{201d}");
+    ret_text = ret_text.replace(r#"""#, CLOSE_DOUBLE.to_string().as_str());
     // ellipses
     ret_text = regx_helper(RegexHelper::Ellipses).replace_all(ret_text.as_str(), "\u{2026}").to_string();
 
+    let (open_single, close_single, open_double, close_double) = quotation_marks(locale);
+
     ret_text
+        .replace(APOSTROPHE, "\u{2019}")
+        .replace(OPEN_SINGLE, open_single)
+        .replace(CLOSE_SINGLE, close_single)
+        .replace(OPEN_DOUBLE, open_double)
+        .replace(CLOSE_DOUBLE, close_double)
+}
+
+fn quotation_marks(locale: SmartypantsLocale) -> (&'static str, &'static str, &'static str, &'static str) {
+    match locale {
+        SmartypantsLocale::En => ("\u{2018}", "\u{2019}", "\u{201c}", "\u{201d}"),
+        SmartypantsLocale::De => ("\u{201a}", "\u{2018}", "\u{201e}", "\u{201c}"),
+        // Guillemets are set off by narrow no-break spaces
+        SmartypantsLocale::Fr => ("\u{2039}\u{202f}", "\u{202f}\u{203a}", "\u{00ab}\u{202f}", "\u{202f}\u{00bb}"),
+        SmartypantsLocale::Ja => ("\u{300e}", "\u{300f}", "\u{300c}", "\u{300d}")
+    }
 }
 
 /**
- * mangle email addresses
+ * mangle email addresses by writing each character as a decimal or hex
+ * entity. The deterministic strategy seeds the choice with the address, so
+ * the same address always renders the same; the random one asks `mangle_rng`
+ * and falls back to the deterministic choice when none is given
  */
-pub fn mangle(text: &str) -> String {
-    // let mut chars = text.chars();
-    let mut out = String::new();
-    let n = text.chars().count();
-    let mut rng = rand::thread_rng();
+pub fn mangle(text: &str, options: &Options) -> String {
+    if options.mangle_strategy == MangleStrategy::Off {
+        return text.to_string();
+    }
 
-    for i in 0..n {
-        let mut ch: String = String::new();
-        let mut c = text.chars().nth(i).unwrap() as u32;
+    let mut state = fnv1a(text);
 
-        if rng.gen::<f64>() > 0.50 {
-            ch.push_str(&format!("x{:x}", c));
-        } else {
-            ch.push_str(&format!("{}", c))
+    let mut random = || -> f64 {
+        match (options.mangle_strategy, options.mangle_rng) {
+            (MangleStrategy::Random, Some(rng)) => rng(),
+            _ => (splitmix64(&mut state) >> 11) as f64 / (1u64 << 53) as f64
         }
+    };
 
-        out.push_str(&format!("&#{};", ch ));
+    let mut out = String::new();
+    for c in text.chars() {
+        if random() > 0.50 {
+            out.push_str(&format!("&#x{:x};", c as u32));
+        } else {
+            out.push_str(&format!("&#{};", c as u32));
+        }
     }
-
     out
-}
\ No newline at end of file
+}
+
+/**
+ * obfuscate an email address for the "css" mangle strategy: written
+ * backwards and turned around again by the browser
+ */
+pub fn reverse_email(text: &str) -> String {
+    let reversed: String = text.chars().rev().collect();
+    format!("<span style=\"unicode-bidi:bidi-override;direction:rtl\">{}</span>", escape(reversed.as_str(), true))
+}
+
+// Stable across platforms and Rust versions, unlike the std hasher
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
@@ -12,6 +12,13 @@ pub mod defaults;
 pub mod renderer;
 pub mod tokenizer;
 pub mod extension;
+pub mod emoji;
+pub mod tasks;
+pub mod source_map;
+pub mod link_references;
+pub mod lint;
+pub mod anchors;
+pub mod sanitizer;
 pub mod text_renderer;
 pub mod regex;
 
@@ -33,7 +40,5 @@ pub fn parse(md: &str)-> String {
     console_error_panic_hook::set_once();
 
     let mut marked = Marked::new(None);
-    let html = marked.parse(md, None, None);
-
-    html
+    marked.parse(md, None, None)
 }
\ No newline at end of file
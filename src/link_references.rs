@@ -0,0 +1,137 @@
+#![allow(warnings, unused)]
+use crate::lexer::{ILexer, Lexer};
+use crate::regex::{RegexHelper, regx_helper};
+use crate::source_map::{SourceMap, Span};
+use crate::defaults::{get_default_options, Options};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ReferenceKind {
+    // [text][label]
+    Full,
+    // [label][]
+    Collapsed,
+    // [label]
+    Shortcut
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Reference {
+    pub label: String,
+    pub kind: ReferenceKind,
+    pub image: bool,
+    pub span: Span
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Definition {
+    pub label: String,
+    pub href: String,
+    pub title: String,
+    pub span: Span,
+    pub references: Vec<Reference>
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReferenceReport {
+    // First definition of every label, with the references resolved by it
+    pub definitions: Vec<Definition>,
+    // Definitions no reference uses
+    pub unused: Vec<Definition>,
+    // Later definitions of an already defined label, which are ignored
+    pub duplicates: Vec<Definition>,
+    // References whose label has no definition
+    pub undefined: Vec<Reference>
+}
+
+/**
+ * Normalize a link label for matching: surrounding whitespace removed, inner
+ * whitespace collapsed and Unicode case folded, so `[ẞ]` matches `[SS]:`
+ */
+pub fn normalize_label(label: &str) -> String {
+    regx_helper(RegexHelper::Spaces)
+        .replace_all(label.trim(), " ")
+        .to_lowercase()
+        .to_uppercase()
+}
+
+/**
+ * Report link reference definitions and their uses. Labels are matched with
+ * CommonMark case folding, which is slightly broader than the lowercasing
+ * used when rendering
+ */
+pub fn inspect_references(src: &str, opt: Option<Options>) -> ReferenceReport {
+    let mut options = if opt.is_some() { opt.unwrap() } else { get_default_options() };
+    // Report references as the document leaves them
+    options.broken_link_callback = None;
+
+    let mut lexer = Lexer::new(options);
+    lexer.lex(src);
+
+    let map = SourceMap::new(src);
+
+    let mut definitions: Vec<Definition> = vec![];
+    let mut duplicates: Vec<Definition> = vec![];
+    let mut cursor: usize = 0;
+
+    for def in lexer.link_definitions.iter() {
+        let raw = def.raw.trim_end_matches('\n');
+        let (pos, len) = map.locate(raw, cursor, map.normalized.len(), true).unwrap_or((cursor, 0));
+        cursor = pos + len;
+
+        let definition = Definition {
+            label: def.label.clone(),
+            href: def.href.clone(),
+            title: def.title.clone(),
+            span: map.span(pos, len),
+            references: vec![]
+        };
+
+        let key = normalize_label(def.label.as_str());
+        if definitions.iter().any(|d| normalize_label(d.label.as_str()) == key) {
+            duplicates.push(definition);
+        } else {
+            definitions.push(definition);
+        }
+    }
+
+    let mut undefined: Vec<Reference> = vec![];
+    cursor = 0;
+
+    for link_ref in lexer.link_references.iter() {
+        let (pos, len) = map.locate(link_ref.raw.as_str(), cursor, map.normalized.len(), false).unwrap_or((cursor, 0));
+        cursor = pos + len;
+
+        let kind = if link_ref.raw.ends_with("[]") {
+            ReferenceKind::Collapsed
+        } else if link_ref.raw.trim_start_matches('!') == format!("[{}]", link_ref.label) {
+            ReferenceKind::Shortcut
+        } else {
+            ReferenceKind::Full
+        };
+
+        let reference = Reference {
+            label: link_ref.label.clone(),
+            kind,
+            image: link_ref.raw.starts_with('!'),
+            span: map.span(pos, len)
+        };
+
+        let key = normalize_label(link_ref.label.as_str());
+        match definitions.iter_mut().find(|d| normalize_label(d.label.as_str()) == key) {
+            Some(definition) => definition.references.push(reference),
+            None             => undefined.push(reference)
+        }
+    }
+
+    let unused = definitions.iter()
+        .filter(|d| d.references.is_empty())
+        .cloned()
+        .collect();
+
+    ReferenceReport {
+        definitions,
+        unused,
+        duplicates,
+        undefined
+    }
+}
@@ -0,0 +1,212 @@
+#![allow(warnings, unused)]
+use std::collections::HashMap;
+
+use crate::slugger::Slugger;
+use crate::lexer::{ILexer, Lexer};
+use crate::parser::heading_text;
+use crate::tokenizer::Token;
+use crate::source_map::{locate_tokens, SourceMap, Span};
+use crate::link_references::{inspect_references, ReferenceKind};
+use crate::defaults::{get_default_options, Options};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Severity {
+    Error,
+    Warning
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rule {
+    pub enabled: bool,
+    pub severity: Severity
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Diagnostic {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span
+}
+
+#[derive(Clone, Copy)]
+pub struct LintOptions {
+    pub options: Options,
+    pub heading_increment: Rule,
+    pub no_duplicate_heading_slugs: Rule,
+    pub no_bare_urls: Rule,
+    pub list_marker_style: Rule,
+    // "consistent" (first list wins), "-", "*" or "+"
+    pub list_marker: &'static str,
+    pub fenced_code_language: Rule,
+    pub image_alt_text: Rule,
+    pub no_trailing_spaces: Rule,
+    // Trailing spaces allowed as a hard line break, 0 to disallow
+    pub br_spaces: usize,
+    pub no_undefined_references: Rule,
+    // Also report `[label]` shortcuts, which are often plain bracketed text
+    pub undefined_shortcuts: bool
+}
+
+pub fn get_default_lint_options() -> LintOptions {
+    let warning = Rule { enabled: true, severity: Severity::Warning };
+
+    LintOptions {
+        options: get_default_options(),
+        heading_increment: warning,
+        no_duplicate_heading_slugs: warning,
+        no_bare_urls: warning,
+        list_marker_style: warning,
+        list_marker: "consistent",
+        fenced_code_language: warning,
+        image_alt_text: warning,
+        no_trailing_spaces: warning,
+        br_spaces: 2,
+        no_undefined_references: Rule { enabled: true, severity: Severity::Error },
+        undefined_shortcuts: false
+    }
+}
+
+/**
+ * Lint `src` with the enabled rules; diagnostics are ordered by position
+ */
+pub fn lint(src: &str, config: &LintOptions) -> Vec<Diagnostic> {
+    let mut lexer = Lexer::new(config.options);
+    let tokens = lexer.lex(src);
+
+    let map = SourceMap::new(src);
+    let mut located = vec![];
+    locate_tokens(&map, tokens, 0, map.normalized.len(), &mut located);
+
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+    let mut report = |rule: &'static str, config: &Rule, message: String, span: Span| {
+        if config.enabled {
+            diagnostics.push(Diagnostic { rule, severity: config.severity, message, span });
+        }
+    };
+
+    let mut previous_depth: usize = 0;
+    let mut slugs: HashMap<String, usize> = HashMap::new();
+    let mut list_marker: Option<char> = if config.list_marker == "consistent" {
+        None
+    } else {
+        config.list_marker.chars().next()
+    };
+
+    for (token, span) in located.into_iter() {
+        let t = token.as_ref().borrow();
+
+        match t._type {
+            "heading" => {
+                if previous_depth > 0 && t.depth > previous_depth + 1 {
+                    report("heading-increment", &config.heading_increment,
+                           format!("Heading levels should only increment by one level at a time: expected h{}, found h{}", previous_depth + 1, t.depth),
+                           span.clone());
+                }
+                previous_depth = t.depth;
+
+                let explicit_id = t.attrs.iter().find(|(k, _)| k == "id").map(|(_, v)| v.clone());
+                let slug = explicit_id.unwrap_or_else(|| {
+                    Slugger::serialize(heading_text(t.tokens.clone(), config.options).as_str())
+                });
+
+                if slugs.contains_key(&slug) {
+                    report("no-duplicate-heading-slugs", &config.no_duplicate_heading_slugs,
+                           format!("Heading slug \"{}\" is already used on line {}", slug, slugs[&slug]),
+                           span);
+                } else {
+                    slugs.insert(slug, span.line);
+                }
+            }
+
+            "link" => {
+                let bare = t.raw.starts_with("http://") || t.raw.starts_with("https://") ||
+                    t.raw.starts_with("ftp://") || t.raw.starts_with("www.") ||
+                    (t.href.starts_with("mailto:") && !t.raw.starts_with('<') && !t.raw.starts_with('['));
+
+                if bare {
+                    report("no-bare-urls", &config.no_bare_urls,
+                           format!("Bare URL used: {}", t.raw),
+                           span);
+                }
+            }
+
+            "list" => {
+                if t.ordered || t.items.is_empty() {
+                    continue;
+                }
+
+                let marker = t.items[0].as_ref().borrow().raw.trim_start().chars().next();
+                if list_marker.is_none() {
+                    list_marker = marker;
+                } else if marker.is_some() && marker != list_marker {
+                    report("list-marker-style", &config.list_marker_style,
+                           format!("Unordered list marker \"{}\" should be \"{}\"", marker.unwrap(), list_marker.unwrap()),
+                           span);
+                }
+            }
+
+            "code" => {
+                if t.code_block_style != "indented" && t.lang.trim().is_empty() {
+                    report("fenced-code-language", &config.fenced_code_language,
+                           String::from("Fenced code blocks should specify a language"),
+                           span);
+                }
+            }
+
+            "image" => {
+                if t.text.trim().is_empty() {
+                    report("image-alt-text", &config.image_alt_text,
+                           format!("Image {} should have alternate text", t.href),
+                           span);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    if config.no_trailing_spaces.enabled {
+        for (pos, len) in trailing_spaces(&map.normalized, config.br_spaces) {
+            report("no-trailing-spaces", &config.no_trailing_spaces,
+                   format!("Expected no trailing spaces, found {}", len),
+                   map.span(pos, len));
+        }
+    }
+
+    if config.no_undefined_references.enabled {
+        for reference in inspect_references(src, Some(config.options)).undefined.into_iter() {
+            if reference.kind == ReferenceKind::Shortcut && !config.undefined_shortcuts {
+                continue;
+            }
+            report("no-undefined-references", &config.no_undefined_references,
+                   format!("Reference \"{}\" is not defined", reference.label),
+                   reference.span);
+        }
+    }
+
+    diagnostics.sort_by_key(|d| (d.span.start, d.span.end));
+    diagnostics
+}
+
+// Runs of trailing whitespace, except `br_spaces` spaces before a line
+// that continues the paragraph
+fn trailing_spaces(src: &str, br_spaces: usize) -> Vec<(usize, usize)> {
+    let lines: Vec<&str> = src.split('\n').collect();
+    let mut found = vec![];
+    let mut pos: usize = 0;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_end_matches(|c| c == ' ' || c == '\t');
+        let len = line.len() - trimmed.len();
+
+        let next_continues = i + 1 < lines.len() && !lines[i + 1].trim().is_empty();
+        let hard_break = br_spaces > 0 && len == br_spaces && !trimmed.trim().is_empty() && next_continues;
+
+        if len > 0 && !hard_break {
+            found.push((pos + trimmed.len(), len));
+        }
+        pos += line.len() + 1;
+    }
+    found
+}
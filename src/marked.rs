@@ -7,8 +7,10 @@ use crate::slugger::Slugger;
 use crate::renderer::Renderer;
 use crate::tokenizer::{Token};
 use crate::lexer::{ILexer, Lexer};
-use crate::parser::{IParser, Parser};
+use crate::parser::{register_heading_ids, IParser, Parser};
 use crate::text_renderer::TextRenderer;
+use crate::tasks::locate_tasks;
+use crate::sanitizer::sanitize_html;
 use crate::defaults::{Callback, get_default_options, Options};
 
 pub struct Marked {
@@ -52,13 +54,25 @@ impl Marked {
         let mut lexer = Lexer::new(self.opt);
         let mut tokens = lexer.lex(src);
 
+        if self.opt.task_list_data {
+            for (item, task) in locate_tasks(src, tokens).into_iter() {
+                item.as_ref().borrow_mut().attrs.push(("data-task-index".to_string(), task.index.to_string()));
+                item.as_ref().borrow_mut().attrs.push(("data-line".to_string(), task.line.to_string()));
+            }
+        }
+
         if self.opt.walk_tokens.is_some() {
             self.walk_tokens(&mut tokens, self.opt.walk_tokens.unwrap())
         }
 
         let mut parser = Parser::new(self.opt);
+        register_heading_ids(tokens, &mut parser.slugger);
+        let out = parser.parse(&mut tokens, true);
 
-        parser.parse(&mut tokens, true)
+        if self.opt.sanitize_output {
+            return sanitize_html(out.as_str(), &self.opt);
+        }
+        out
     }
 
     pub fn parse(&mut self, src: &str, opt: Option<Options>, callback: Option<Callback>) -> String {
@@ -99,7 +113,7 @@ impl Marked {
                     break;
                 }
 
-                "list"      => {
+                "list" | "dl" => {
                     self.walk_tokens(&mut token.as_ref().borrow_mut().items, callback);
                     break;
                 }
@@ -116,6 +130,27 @@ impl Marked {
 
     }
 
+    // Targets of every `[[wiki link]]` in the document, in order of first appearance
+    pub fn wiki_link_targets(&mut self, src: &str, opt: Option<Options>) -> Vec<String> {
+        let mut options = if opt.is_some() { opt.unwrap() } else { get_default_options() };
+        options.wiki_links = true;
+
+        let mut lexer = Lexer::new(options);
+        let tokens = lexer.lex(src);
+
+        let mut found = vec![];
+        collect_tokens(tokens, "wikilink", &mut found);
+
+        let mut targets: Vec<String> = vec![];
+        for token in found.iter() {
+            let target = token.as_ref().borrow().tag.clone();
+            if !target.is_empty() && !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+        targets
+    }
+
     pub fn parse_inline(&mut self, src: &str, opt: Option<Options>) -> String {
         // TODO: Skipping pre-flight checks for now
 
@@ -138,4 +173,21 @@ impl Marked {
 
         self.parser.parse_inline(&mut tokens, self.renderer)
     }
-}
\ No newline at end of file
+}
+
+// Depth-first search for tokens of the given type, including list items and table cells
+pub fn collect_tokens(tokens: &Vec<Rc<RefCell<Token>>>, _type: &str, found: &mut Vec<Rc<RefCell<Token>>>) {
+    for token in tokens.iter() {
+        if token.as_ref().borrow()._type == _type {
+            found.push(Rc::clone(token));
+        }
+
+        let t = token.as_ref().borrow();
+        collect_tokens(&t.tokens, _type, found);
+        collect_tokens(&t.items, _type, found);
+        collect_tokens(&t.header, _type, found);
+        for row in t.rows.iter() {
+            collect_tokens(row, _type, found);
+        }
+    }
+}
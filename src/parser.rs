@@ -4,8 +4,11 @@ use std::cell::RefCell;
 
 use crate::tokenizer::Token;
 use crate::slugger::Slugger;
-use crate::defaults::Options;
-use crate::helpers::unescape;
+use crate::lexer::mangle;
+use crate::marked::collect_tokens;
+use crate::defaults::{Options, UrlKind};
+use crate::helpers::{escape, unescape};
+use crate::regex::{RegexHelper, regx_helper};
 use crate::renderer::{Flags, IRenderer, Renderer};
 use crate::text_renderer::{ITextRenderer, TextRenderer};
 
@@ -31,6 +34,78 @@ impl Parser {
             slugger: Slugger::new()
         }
     }
+
+    // Pass a link or image URL through the URL rewriter; None drops it
+    fn rewrite_url(&self, token: &Token) -> Option<String> {
+        if self.options.url_rewriter.is_none() {
+            return Some(token.href.clone());
+        }
+
+        let kind = if token._type == "image" {
+            UrlKind::Image
+        } else if token._type == "link" && !token.raw.starts_with('[') {
+            UrlKind::Autolink
+        } else {
+            UrlKind::Link
+        };
+
+        (self.options.url_rewriter.unwrap())(token.href.as_str(), kind, token)
+    }
+
+    // Email autolinks reach the URL rewriter as plain `mailto:` links and are
+    // mangled like their text afterwards
+    fn mangle_email(&self, href: String, token: &Token) -> String {
+        let autolink = token.raw.trim_start_matches('<');
+        let email = !token.raw.starts_with('[') && !autolink.to_lowercase().starts_with("mailto:");
+
+        if !self.options.mangle || !email || !href.starts_with("mailto:") {
+            return href;
+        }
+
+        format!("mailto:{}", escape(mangle(&href["mailto:".len()..], &self.options).as_str(), false))
+    }
+
+    // A paragraph holding nothing but an image, as a figure captioned with
+    // the image title
+    fn figure(&mut self, tokens: &Vec<Rc<RefCell<Token>>>) -> Option<String> {
+        let content: Vec<&Rc<RefCell<Token>>> = tokens.iter()
+            .filter(|t| !(t.as_ref().borrow()._type == "text" && t.as_ref().borrow().text.trim().is_empty()))
+            .collect();
+
+        if content.len() != 1 || content[0].as_ref().borrow()._type != "image" {
+            return None;
+        }
+
+        let mut image = content[0].as_ref().borrow().clone();
+        let caption = std::mem::take(&mut image.title);
+
+        let image = self.parse_inline(&mut vec![Rc::new(RefCell::new(image))], Renderer::new(self.options));
+        if image.is_empty() {
+            return None;
+        }
+        Some(self.renderer.figure(image.as_str(), caption.as_str()))
+    }
+
+    // Rewrite the `href` and `src` attributes of raw HTML, removing those the
+    // URL rewriter drops
+    fn rewrite_html_urls(&self, token: &Token) -> String {
+        if self.options.url_rewriter.is_none() {
+            return token.text.clone();
+        }
+
+        let rewriter = self.options.url_rewriter.unwrap();
+
+        regx_helper(RegexHelper::HtmlUrlAttribute).replace_all(token.text.as_str(), |caps: &regex::Captures| {
+            let name = caps.get(2).map_or("", |m| m.as_str());
+            let value = caps.get(4).or(caps.get(5)).or(caps.get(6)).map_or("", |m| m.as_str());
+            let kind = if name.eq_ignore_ascii_case("src") { UrlKind::HtmlSrc } else { UrlKind::HtmlHref };
+
+            match rewriter(html_escape::decode_html_entities(value).as_ref(), kind, token) {
+                Some(url) => format!("{}{}{}\"{}\"", &caps[1], name, &caps[3], escape(url.as_str(), true)),
+                None      => String::new()
+            }
+        }).to_string()
+    }
 }
 
 impl IParser for Parser {
@@ -102,7 +177,7 @@ impl IParser for Parser {
                         };
 
                         let raw = unescape(_raw.as_str());
-                        let _out = self.renderer.heading(text.as_str(), level, raw.as_str(), &mut self.slugger);
+                        let _out = self.renderer.heading(text.as_str(), level, raw.as_str(), &mut self.slugger, &heading_token_rc.attrs);
 
                         out.push_str(_out.as_str());
                     }
@@ -120,7 +195,8 @@ impl IParser for Parser {
                             out.push_str(self.renderer.code(
                                 code_token_rc.text.as_str(),
                                 code_token_rc.lang.as_str(),
-                                code_token_rc.escaped
+                                code_token_rc.escaped,
+                                &code_token_rc.attrs
                             ).as_str());
                         }
                     }
@@ -138,19 +214,26 @@ impl IParser for Parser {
                         cell = "".to_string();
                         l2 = table_token.header.len();
 
+                        let mut col = 0;
                         for j in 0..l2 {
                             {
-                                let header_tokens = &mut table_token.header[j].as_ref().borrow_mut().tokens;
+                                let mut header_token = table_token.header[j].as_ref().borrow_mut();
+                                let colspan = get_colspan(&header_token.attrs);
 
-                                let align = table_token.align[j].clone();
+                                let align = table_token.align[col].clone();
+                                col += colspan;
 
                                 let flags = Flags {
                                     header: true,
                                     align
                                 };
 
-                                let content = self.parse_inline(header_tokens, self.renderer);
-                                let cells = self.renderer.tablecell(content.as_str(), flags);
+                                let content = self.parse_inline(&mut header_token.tokens, self.renderer);
+                                let cells = if colspan > 1 {
+                                    self.renderer.tablecell_span(content.as_str(), flags, colspan)
+                                } else {
+                                    self.renderer.tablecell(content.as_str(), flags)
+                                };
 
                                 cell = format!("{}{}",
                                                cell,
@@ -175,18 +258,25 @@ impl IParser for Parser {
                                 cell = "".to_string();
                                 l3 = row.len();
 
+                                let mut col = 0;
                                 for k in 0..l3 {
 
                                     let curr_token = row.get_mut(k).unwrap();
-                                    let row_tokens = &mut curr_token.as_ref().borrow_mut().tokens;
+                                    let mut cell_token = curr_token.as_ref().borrow_mut();
+                                    let colspan = get_colspan(&cell_token.attrs);
 
                                     let flags = Flags {
                                         header: false,
-                                        align: aligns[k].clone()
+                                        align: aligns[col].clone()
                                     };
+                                    col += colspan;
 
-                                    let content = self.parse_inline(row_tokens, self.renderer);
-                                    let cells = self.renderer.tablecell(content.as_str(), flags);
+                                    let content = self.parse_inline(&mut cell_token.tokens, self.renderer);
+                                    let cells = if colspan > 1 {
+                                        self.renderer.tablecell_span(content.as_str(), flags, colspan)
+                                    } else {
+                                        self.renderer.tablecell(content.as_str(), flags)
+                                    };
 
                                     cell = format!("{}{}",
                                                    cell,
@@ -199,9 +289,16 @@ impl IParser for Parser {
                                 );
                             }
                         }
+                        let table = if table_token.tokens.len() > 0 {
+                            let caption = self.parse_inline(&mut table_token.tokens, self.renderer);
+                            self.renderer.table_with_caption(caption.as_str(), header.as_str(), body.as_str())
+                        } else {
+                            self.renderer.table(header.as_str(), body.as_str())
+                        };
+
                         out = format!("{}{}",
                                       out,
-                                      self.renderer.table(header.as_str(), body.as_str())
+                                      table
                         );
                     }
                     i += 1;
@@ -215,6 +312,23 @@ impl IParser for Parser {
                     continue;
                 }
 
+                "alert"         => {
+                    let alert_type = token.as_ref().borrow().tag.clone();
+                    body = self.parse(&mut token.as_ref().borrow_mut().tokens, true);
+                    out.push_str(self.renderer.alert(body.as_str(), alert_type.as_str()).as_str());
+                    i += 1;
+                    continue;
+                }
+
+                "container"     => {
+                    let name = token.as_ref().borrow().tag.clone();
+                    let args = token.as_ref().borrow().title.clone();
+                    body = self.parse(&mut token.as_ref().borrow_mut().tokens, true);
+                    out.push_str(self.renderer.container(body.as_str(), name.as_str(), args.as_str()).as_str());
+                    i += 1;
+                    continue;
+                }
+
                 "list"          => {
                     let mut list_token = token.as_ref().borrow_mut();
 
@@ -230,7 +344,12 @@ impl IParser for Parser {
 
                         item_body = "".to_string();
                         if item.as_ref().borrow().task {
-                            checkbox = self.renderer.checkbox(checked);
+                            checkbox = if self.options.task_list_data || self.options.task_list_interactive {
+                                let attrs = item.as_ref().borrow().attrs.clone();
+                                self.renderer.task_checkbox(checked, &attrs)
+                            } else {
+                                self.renderer.checkbox(checked)
+                            };
 
                             if loose {
                                 if item.as_ref().borrow().tokens.len() > 0 &&
@@ -281,7 +400,8 @@ impl IParser for Parser {
                                                                align: vec![],
                                                                rows: vec![],
                                                                header: vec![],
-                                                               code_block_style: "".to_string()
+                                                               code_block_style: "".to_string(),
+                                                               attrs: vec![]
                                                            }
                                                        )))
                                 }
@@ -311,13 +431,45 @@ impl IParser for Parser {
                     continue;
                 }
 
+                "dl"            => {
+                    let mut dl_token = token.as_ref().borrow_mut();
+
+                    body = "".to_string();
+                    for item in dl_token.items.iter_mut() {
+                        let item_type = item.as_ref().borrow()._type;
+                        let item_loose = item.as_ref().borrow().loose;
+
+                        if item_type == "dt" {
+                            let text = self.parse_inline(&mut item.as_ref().borrow_mut().tokens, self.renderer);
+                            body.push_str(self.renderer.dt(text.as_str()).as_str());
+                        } else {
+                            let text = self.parse(&mut item.as_ref().borrow_mut().tokens, item_loose);
+                            body.push_str(self.renderer.dd(text.as_str()).as_str());
+                        }
+                    }
+
+                    out.push_str(self.renderer.dl(body.as_str()).as_str());
+                    i += 1;
+                    continue;
+                }
+
                 "html"          => {
-                    out.push_str(self.renderer.html(token.as_ref().borrow().text.as_str()).as_str());
+                    let html = self.rewrite_html_urls(&token.as_ref().borrow());
+                    out.push_str(self.renderer.html(html.as_str()).as_str());
                     i += 1;
                     continue;
                 }
 
                 "paragraph"     => {
+                    if self.options.image_figures {
+                        let figure = self.figure(&token.as_ref().borrow().tokens);
+                        if figure.is_some() {
+                            out.push_str(figure.unwrap().as_str());
+                            i += 1;
+                            continue;
+                        }
+                    }
+
                     let text_ = self.parse_inline(&mut token.as_ref().borrow_mut().tokens, Renderer::new(self.options));
                     let _text = self.renderer.paragraph(text_.as_str());
                     out.push_str(_text.as_str());
@@ -402,23 +554,27 @@ impl IParser for Parser {
 
                 "html"          => {
                     {
-                        let html_token = token.as_ref().borrow();
-                        out.push_str(renderer.html(html_token.text.as_str()).as_str());
+                        let html = self.rewrite_html_urls(&token.as_ref().borrow());
+                        out.push_str(renderer.html(html.as_str()).as_str());
                     }
                     continue;
                 }
 
-                "link"          => {
+                "link" | "wikilink" => {
 
                     {
                         let mut link_token = token.as_ref().borrow_mut();
                         let _text = self.parse_inline(&mut link_token.tokens, renderer);
 
-                        out.push_str(renderer.link(
-                            link_token.href.as_str(),
-                            link_token.title.as_str(),
-                            _text.as_str()
-                        ).as_str());
+                        match self.rewrite_url(&link_token) {
+                            Some(href) => out.push_str(renderer.link(
+                                self.mangle_email(href, &link_token).as_str(),
+                                link_token.title.as_str(),
+                                _text.as_str(),
+                                &link_token.attrs
+                            ).as_str()),
+                            None       => out.push_str(_text.as_str())
+                        }
                     }
 
                     continue;
@@ -428,11 +584,17 @@ impl IParser for Parser {
 
                     {
                         let mut image_token = token.as_ref().borrow();
+                        let href = self.rewrite_url(&image_token);
+
+                        if href.is_none() {
+                            continue;
+                        }
 
                         out.push_str(renderer.image(
-                            image_token.href.as_str(),
+                            href.unwrap().as_str(),
                             image_token.title.as_str(),
-                            image_token.text.as_str()
+                            image_token.text.as_str(),
+                            &image_token.attrs
                         ).as_str());
                     }
 
@@ -482,6 +644,37 @@ impl IParser for Parser {
                     continue;
                 }
 
+                "mark" | "sup" | "sub" | "ins" => {
+                    {
+                        let mut em_token = token.as_ref().borrow_mut();
+                        let _text = self.parse_inline(&mut em_token.tokens, renderer);
+                        let rendered = match em_token._type {
+                            "mark" => renderer.mark(_text.as_str()),
+                            "sup" => renderer.sup(_text.as_str()),
+                            "sub" => renderer.sub(_text.as_str()),
+                            _ => renderer.ins(_text.as_str())
+                        };
+                        out.push_str(rendered.as_str());
+                    }
+                    continue;
+                }
+
+                "abbr"          => {
+                    {
+                        let abbr_token = token.as_ref().borrow();
+                        out.push_str(renderer.abbr(abbr_token.text.as_str(), abbr_token.title.as_str()).as_str());
+                    }
+                    continue;
+                }
+
+                "emoji"         => {
+                    {
+                        let emoji_token = token.as_ref().borrow();
+                        out.push_str(renderer.emoji(emoji_token.tag.as_str(), emoji_token.text.as_str()).as_str());
+                    }
+                    continue;
+                }
+
                 "text"          => {
                     {
                         let text_token = token.as_ref().borrow();
@@ -544,7 +737,7 @@ impl IParser for Parser {
                     continue;
                 }
 
-                "link"          => {
+                "link" | "wikilink" => {
 
                     {
                         let mut link_token = token.as_ref().borrow_mut();
@@ -611,6 +804,37 @@ impl IParser for Parser {
                     continue;
                 }
 
+                "mark" | "sup" | "sub" | "ins" => {
+                    {
+                        let mut em_token = token.as_ref().borrow_mut();
+                        let _text = self.parse_inline_tr(&mut em_token.tokens, renderer);
+                        let rendered = match em_token._type {
+                            "mark" => renderer.mark(_text.as_str()),
+                            "sup" => renderer.sup(_text.as_str()),
+                            "sub" => renderer.sub(_text.as_str()),
+                            _ => renderer.ins(_text.as_str())
+                        };
+                        out.push_str(rendered.as_str());
+                    }
+                    continue;
+                }
+
+                "abbr"          => {
+                    {
+                        let abbr_token = token.as_ref().borrow();
+                        out.push_str(renderer.abbr(abbr_token.text.as_str(), abbr_token.title.as_str()).as_str());
+                    }
+                    continue;
+                }
+
+                "emoji"         => {
+                    {
+                        let emoji_token = token.as_ref().borrow();
+                        out.push_str(renderer.emoji(emoji_token.tag.as_str(), emoji_token.text.as_str()).as_str());
+                    }
+                    continue;
+                }
+
                 "text"          => {
                     {
                         let text_token = token.as_ref().borrow();
@@ -635,10 +859,38 @@ impl IParser for Parser {
 
 pub fn parse(mut tokens: &mut Vec<Rc<RefCell<Token>>>, options: Options) -> String {
     let mut parser = Parser::new(options);
+    register_heading_ids(tokens, &mut parser.slugger);
     parser.parse(&mut tokens, true)
 }
 
 pub fn parse_inline(mut tokens: Vec<Rc<RefCell<Token>>>, options: Options) -> String {
     let mut parser = Parser::new(options);
     parser.parse_inline(&mut tokens, parser.renderer)
-}
\ No newline at end of file
+}
+
+// Reserve the explicit heading ids of a document up front, so a generated
+// slug never takes one, even when its heading comes first
+pub fn register_heading_ids(tokens: &Vec<Rc<RefCell<Token>>>, slugger: &mut Slugger) {
+    let mut headings = vec![];
+    collect_tokens(tokens, "heading", &mut headings);
+
+    for heading in headings.iter() {
+        if let Some((_, id)) = heading.as_ref().borrow().attrs.iter().find(|(k, _)| k == "id") {
+            slugger.register(id);
+        }
+    }
+}
+
+// Plain text of a heading, as passed to the slugger for its id
+pub fn heading_text(mut tokens: Vec<Rc<RefCell<Token>>>, options: Options) -> String {
+    let mut parser = Parser::new(options);
+    let text = parser.parse_inline_tr(&mut tokens, TextRenderer::new());
+    unescape(text.as_str())
+}
+
+fn get_colspan(attrs: &[(String, String)]) -> usize {
+    attrs.iter()
+        .find(|(key, _)| key == "colspan")
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(1)
+}
@@ -64,7 +64,7 @@ lazy_static! {
 
 
     // Regex Sets
-    static ref CODE_CHARS_ON_BOTH_ENDS: RegexSet = RegexSet::new(&[ r#"^ "#, r#" $"# ]).unwrap();
+    static ref CODE_CHARS_ON_BOTH_ENDS: RegexSet = RegexSet::new([ r#"^ "#, r#" $"# ]).unwrap();
 
     // Fancy
     static ref LINK_CAPTURES: fancy_regex::Regex = fancy_regex::Regex::new(r#"^([^'"]*[^\s])\s+(['"])(.*)\2"#).unwrap();
@@ -73,6 +73,39 @@ lazy_static! {
     static ref RAW_BLOCK_END: fancy_regex::Regex = fancy_regex::Regex::new(r#"(?i)^<\/(pre|code|kbd|script)(\s|>)"#).unwrap();
 }
 
+// Markdown extensions
+lazy_static! {
+    static ref DEFINITION_MARKER: Regex = Regex::new(r#"^ {0,3}:(?:[ \t]+|$)"#).unwrap();
+    static ref TRAILING_ATTRIBUTES: Regex = Regex::new(r#"[ \t]*\{([^{}\n]*)\}[ \t]*$"#).unwrap();
+    static ref LEADING_ATTRIBUTES: Regex = Regex::new(r#"^\{([^{}\n]*)\}"#).unwrap();
+    static ref ATTRIBUTE_LIST: Regex = Regex::new(r#"^\s*(?:(?:#[\w-]+|\.[\w-]+|[\w:-]+=(?:"[^"]*"|'[^']*'|[^\s"'{}]+))\s*)+$"#).unwrap();
+    static ref ATTRIBUTE: Regex = Regex::new(r#"#([\w-]+)|\.([\w-]+)|([\w:-]+)=(?:"([^"]*)"|'([^']*)'|([^\s"'{}]+))"#).unwrap();
+    static ref ALERT_MARKER: Regex = Regex::new(r#"^ *\[!([A-Za-z]+)\][ \t]*(?:\n|$)"#).unwrap();
+    static ref EMOJI_SHORTCODE: Regex = Regex::new(r#"^:([a-z0-9_+-]+):"#).unwrap();
+    static ref REFERENCE_ISSUE: Regex = Regex::new(r#"^(?:([\w.-]+/[\w.-]+))?#(\d+)\b"#).unwrap();
+    static ref REFERENCE_COMMIT: Regex = Regex::new(r#"^(?:([\w.-]+/[\w.-]+)@)?([0-9a-f]{7,40})\b"#).unwrap();
+    static ref REFERENCE_MENTION: Regex = Regex::new(r#"^@([A-Za-z0-9](?:[A-Za-z0-9-]{0,37}[A-Za-z0-9])?)\b"#).unwrap();
+    static ref WIKI_LINK: Regex = Regex::new(r#"^\[\[([^\[\]|\n]*?)(?:\|([^\[\]\n]+))?\]\]"#).unwrap();
+    static ref ABBREVIATION_DEF: Regex = Regex::new(r#"^ {0,3}\*\[([^\]\n]+)\]:[ \t]*([^\n]*?)[ \t]*(?:\n+|$)"#).unwrap();
+    static ref TABLE_CAPTION: Regex = Regex::new(r#"^ {0,3}(?:Table:[ \t]*(\S[^\n]*?)|\[([^\[\]\n]+)\])[ \t]*(?:\n+|$)"#).unwrap();
+    static ref CONTAINER_OPEN: Regex = Regex::new(r#"^ {0,3}(:{3,})[ \t]*([A-Za-z][\w-]*)(?:[ \t]+([^\n]*?))?[ \t]*$"#).unwrap();
+    static ref CONTAINER_CLOSE: Regex = Regex::new(r#"^ {0,3}(:{3,})[ \t]*$"#).unwrap();
+    static ref CONTAINER_CODE_FENCE: Regex = Regex::new(r#"^ {0,3}(`{3,}|~{3,})"#).unwrap();
+    static ref SMARTY_ELISION: Regex = Regex::new(r#"(^|[\s(\[{"\u2014-])'((?i:tis|twas|til|em|cause)\b|\d0s\b|\d{2}\b)"#).unwrap();
+    static ref SMARTY_ROCK_N_ROLL: Regex = Regex::new(r#"(^|\s)'n'(\s|$)"#).unwrap();
+    static ref SMARTY_INTRAWORD: Regex = Regex::new(r#"([\p{L}\p{N}])'([\p{L}\p{N}])"#).unwrap();
+    static ref SMARTY_OPEN_DOUBLES: Regex = Regex::new(r#"(^|[-\u2014/(\[{\x{E001}\s])""#).unwrap();
+    static ref SMARTY_SYMBOL: Regex = Regex::new(r#"(?i)\((c|r|tm)\)"#).unwrap();
+    static ref SMARTY_FRACTION: Regex = Regex::new(r#"(^|[^\d/])(1/2|1/4|3/4)($|[^\d/])"#).unwrap();
+    static ref HTML_ID_ATTRIBUTE: Regex = Regex::new(r#"(?i)\s(?:id|name)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#).unwrap();
+    static ref TAG_FILTER: Regex = Regex::new(r#"(?i)<(/?(?:title|textarea|style|xmp|iframe|noembed|noframes|script|plaintext)(?:[\s/>]|$))"#).unwrap();
+    static ref HTML_URL_ATTRIBUTE: Regex = Regex::new(r#"(?i)(\s)(href|src)(\s*=\s*)(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#).unwrap();
+    static ref IMAGE_SIZE_HINT: Regex = Regex::new(r#"^([ \t\n]+=(\d*)x(\d*))[\s)]"#).unwrap();
+    static ref ANCHOR_TAG: Regex = Regex::new(r#"(?i)<a[\s>]"#).unwrap();
+    static ref REFERENCE_START: Regex = Regex::new(r#"(?:[\w.-]+/[\w.-]+)?#\d+\b|(?:[\w.-]+/[\w.-]+@)?[0-9a-f]{7,40}\b|@[A-Za-z0-9]"#).unwrap();
+    static ref RAW_TEXT_TAG_START: Regex = Regex::new(r#"(?i)^<(script|style|textarea|title)[\s/>]"#).unwrap();
+}
+
 pub enum RegexHelper {
     LineDown,
     Newline,
@@ -124,7 +157,36 @@ pub enum RegexHelper {
     EndWithNewline,
     SerializeHtml,
     SerializeChars,
-    SerializeSpaces
+    SerializeSpaces,
+    AlertMarker,
+    DefinitionMarker,
+    TrailingAttributes,
+    LeadingAttributes,
+    AttributeList,
+    Attribute,
+    EmojiShortcode,
+    ReferenceIssue,
+    ReferenceCommit,
+    ReferenceMention,
+    WikiLink,
+    AbbreviationDef,
+    TableCaption,
+    ContainerOpen,
+    ContainerClose,
+    ContainerCodeFence,
+    SmartyElision,
+    SmartyRockNRoll,
+    SmartyIntraword,
+    SmartyOpenDoubles,
+    SmartySymbol,
+    SmartyFraction,
+    HtmlIdAttribute,
+    TagFilter,
+    HtmlUrlAttribute,
+    ImageSizeHint,
+    AnchorTag,
+    ReferenceStart,
+    RawTextTagStart
 }
 
 pub enum RegexHelperFc {
@@ -191,6 +253,35 @@ pub fn regx_helper<'a>(re: RegexHelper) -> &'a Regex {
         RegexHelper::SerializeHtml          => { &SERIALIZE_HTML }
         RegexHelper::SerializeChars         => { &SERIALIZE_CHARS }
         RegexHelper::SerializeSpaces        => { &SERIALIZE_SPACES }
+        RegexHelper::AlertMarker            => { &ALERT_MARKER }
+        RegexHelper::DefinitionMarker       => { &DEFINITION_MARKER }
+        RegexHelper::TrailingAttributes     => { &TRAILING_ATTRIBUTES }
+        RegexHelper::LeadingAttributes      => { &LEADING_ATTRIBUTES }
+        RegexHelper::AttributeList          => { &ATTRIBUTE_LIST }
+        RegexHelper::Attribute              => { &ATTRIBUTE }
+        RegexHelper::EmojiShortcode         => { &EMOJI_SHORTCODE }
+        RegexHelper::ReferenceIssue         => { &REFERENCE_ISSUE }
+        RegexHelper::ReferenceCommit        => { &REFERENCE_COMMIT }
+        RegexHelper::ReferenceMention       => { &REFERENCE_MENTION }
+        RegexHelper::WikiLink               => { &WIKI_LINK }
+        RegexHelper::AbbreviationDef        => { &ABBREVIATION_DEF }
+        RegexHelper::TableCaption           => { &TABLE_CAPTION }
+        RegexHelper::ContainerOpen          => { &CONTAINER_OPEN }
+        RegexHelper::ContainerClose         => { &CONTAINER_CLOSE }
+        RegexHelper::ContainerCodeFence     => { &CONTAINER_CODE_FENCE }
+        RegexHelper::SmartyElision          => { &SMARTY_ELISION }
+        RegexHelper::SmartyRockNRoll        => { &SMARTY_ROCK_N_ROLL }
+        RegexHelper::SmartyIntraword        => { &SMARTY_INTRAWORD }
+        RegexHelper::SmartyOpenDoubles      => { &SMARTY_OPEN_DOUBLES }
+        RegexHelper::SmartySymbol           => { &SMARTY_SYMBOL }
+        RegexHelper::SmartyFraction         => { &SMARTY_FRACTION }
+        RegexHelper::HtmlIdAttribute        => { &HTML_ID_ATTRIBUTE }
+        RegexHelper::TagFilter              => { &TAG_FILTER }
+        RegexHelper::HtmlUrlAttribute       => { &HTML_URL_ATTRIBUTE }
+        RegexHelper::ImageSizeHint          => { &IMAGE_SIZE_HINT }
+        RegexHelper::AnchorTag              => { &ANCHOR_TAG }
+        RegexHelper::ReferenceStart         => { &REFERENCE_START }
+        RegexHelper::RawTextTagStart        => { &RAW_TEXT_TAG_START }
     }
 }
 
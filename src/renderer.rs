@@ -1,8 +1,10 @@
 #![allow(warnings, unused)]
 use crate::lexer::regx;
 use crate::slugger::Slugger;
-use crate::defaults::Options;
-use crate::helpers::{clean_url, escape};
+use crate::emoji;
+use crate::defaults::{HeadingAnchors, HtmlMode, Options};
+use crate::sanitizer::sanitize_html;
+use crate::helpers::{classify_link, clean_url, escape, filter_disallowed_tags, LinkKind};
 use crate::regex::{RegexHelper, regx_helper};
 
 // #[derive(Clone)]
@@ -16,6 +18,76 @@ impl Renderer {
             options
         }
     }
+
+    // Heading text with a permalink to the heading placed "before" or "after"
+    // it, or wrapping it. The symbol may be any HTML, such as an inline SVG
+    fn heading_anchor(&self, text: &str, id: &str, level: usize) -> String {
+        if !self.options.heading_anchor_levels.contains(&level) {
+            return text.to_string();
+        }
+
+        let open = format!("<a class=\"{}\" href=\"#{}\"", escape(self.options.heading_anchor_class, false), escape(id, false));
+        let symbol = self.options.heading_anchor_symbol;
+
+        // Links do not nest, so a heading holding one is not wrapped
+        let mode = match self.options.heading_anchors {
+            HeadingAnchors::Wrap if regx_helper(RegexHelper::AnchorTag).is_match(text) => HeadingAnchors::After,
+            mode => mode
+        };
+
+        match mode {
+            HeadingAnchors::Before => format!("{} aria-hidden=\"true\">{}</a> {}", open, symbol, text),
+            HeadingAnchors::After  => format!("{} {} aria-hidden=\"true\">{}</a>", text, open, symbol),
+            HeadingAnchors::Wrap   => format!("{}>{}</a>", open, text),
+            HeadingAnchors::Off    => text.to_string()
+        }
+    }
+
+    // Attributes of an image with dimensions from the image_dimensions hook,
+    // unless given in the source, and the loading and decoding hints
+    fn image_attrs(&self, src: &str, attrs: &[(String, String)]) -> Vec<(String, String)> {
+        let mut out = attrs.to_vec();
+        let has = |out: &Vec<(String, String)>, key: &str| out.iter().any(|(k, _)| k == key);
+
+        if self.options.image_dimensions.is_some() && !has(&out, "width") && !has(&out, "height") {
+            if let Some((width, height)) = (self.options.image_dimensions.unwrap())(src) {
+                out.push(("width".to_string(), width.to_string()));
+                out.push(("height".to_string(), height.to_string()));
+            }
+        }
+
+        for (key, value) in [("loading", self.options.image_loading), ("decoding", self.options.image_decoding)] {
+            if !value.is_empty() && !has(&out, key) {
+                out.push((key.to_string(), value.to_string()));
+            }
+        }
+        out
+    }
+
+    // Attributes of a link with the external link policy applied; attributes
+    // given in the source take precedence
+    fn link_policy_attrs(&self, href: &str, attrs: &[(String, String)]) -> Vec<(String, String)> {
+        if !self.options.external_links {
+            return attrs.to_vec();
+        }
+
+        let policy = match classify_link(href, self.options.own_hosts) {
+            LinkKind::External => vec![
+                ("rel", self.options.external_link_rel),
+                ("target", self.options.external_link_target),
+                ("class", self.options.external_link_class)
+            ],
+            LinkKind::Mailto   => vec![("class", self.options.mailto_link_class)],
+            LinkKind::Internal => vec![]
+        };
+
+        let mut out: Vec<(String, String)> = policy.into_iter()
+            .filter(|(key, value)| !value.is_empty() && !attrs.iter().any(|(k, _)| k == key))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        out.extend(attrs.iter().cloned());
+        out
+    }
 }
 
 impl Clone for Renderer {
@@ -26,6 +98,17 @@ impl Clone for Renderer {
 
 impl Copy for Renderer {}
 
+/**
+ * render `{#id .cls key=value}` attributes as ` id="id" class="cls" key="value"`
+ */
+pub fn render_attrs(attrs: &[(String, String)]) -> String {
+    let mut out = String::from("");
+    for (key, value) in attrs.iter() {
+        out.push_str(format!(r#" {}="{}""#, key, escape(value, false)).as_str());
+    }
+    out
+}
+
 pub struct Flags {
     pub header: bool,
     pub align: String
@@ -33,18 +116,28 @@ pub struct Flags {
 
 
 pub trait IRenderer {
-    fn code(&mut self, code: &str, info_str: &str, escaped: bool) -> String;
+    fn code(&mut self, code: &str, info_str: &str, escaped: bool, attrs: &[(String, String)]) -> String;
+    fn diagram(&mut self, source: &str, lang: &str, attrs: &[(String, String)]) -> String;
     fn blockquote(&mut self, quote: &str) -> String;
+    fn alert(&mut self, body: &str, alert_type: &str) -> String;
+    fn container(&mut self, body: &str, name: &str, args: &str) -> String;
     fn html(&mut self, html: &str) -> String;
-    fn heading(&mut self, text: &str, level: usize, raw: &str, slugger: &mut Slugger) -> String;
+    fn heading(&mut self, text: &str, level: usize, raw: &str, slugger: &mut Slugger, attrs: &[(String, String)]) -> String;
     fn hr(&mut self) -> String;
     fn list(&mut self, body: &str, ordered: bool, start: i32) -> String;
     fn list_item(&mut self, text: &str) -> String;
     fn checkbox(&mut self,checked: bool) -> String;
+    fn task_checkbox(&mut self, checked: bool, attrs: &[(String, String)]) -> String;
+    fn dl(&mut self, body: &str) -> String;
+    fn dt(&mut self, text: &str) -> String;
+    fn dd(&mut self, text: &str) -> String;
     fn paragraph(&mut self, text: &str) -> String;
+    fn figure(&mut self, image: &str, caption: &str) -> String;
     fn table(&mut self, header: &str, body: &str) -> String;
     fn tablerow(&mut self, content: &str) -> String;
     fn tablecell(&mut self, content: &str, flags: Flags) -> String;
+    fn table_with_caption(&mut self, caption: &str, header: &str, body: &str) -> String;
+    fn tablecell_span(&mut self, content: &str, flags: Flags, colspan: usize) -> String;
 
     // Span Level Renderer
     fn strong(&mut self, text: &str) -> String;
@@ -52,18 +145,28 @@ pub trait IRenderer {
     fn codespan(&mut self, text: &str) -> String;
     fn br(&mut self) -> String;
     fn del(&mut self, text: &str) -> String;
-    fn link(&mut self, href: &str, title: &str, text: &str) -> String;
-    fn image(&mut self, href: &str, title: &str, text: &str) -> String;
+    fn mark(&mut self, text: &str) -> String;
+    fn sup(&mut self, text: &str) -> String;
+    fn sub(&mut self, text: &str) -> String;
+    fn ins(&mut self, text: &str) -> String;
+    fn emoji(&mut self, name: &str, unicode: &str) -> String;
+    fn abbr(&mut self, text: &str, title: &str) -> String;
+    fn link(&mut self, href: &str, title: &str, text: &str, attrs: &[(String, String)]) -> String;
+    fn image(&mut self, href: &str, title: &str, text: &str, attrs: &[(String, String)]) -> String;
     fn text(&mut self, text: &str) -> String;
 }
 
 impl IRenderer for Renderer {
-    fn code(&mut self, mut code: &str, info_str: &str, mut escaped: bool) -> String {
+    fn code(&mut self, mut code: &str, info_str: &str, mut escaped: bool, attrs: &[(String, String)]) -> String {
 
         let mut _code = String::from(code);
         let lang_caps = regx_helper(RegexHelper::CodeSpaces).captures(info_str).unwrap();
         let lang = lang_caps.get(0).map_or("", |m| m.as_str());
 
+        if self.options.diagrams && self.options.diagram_languages.iter().any(|l| l.eq_ignore_ascii_case(lang)) {
+            return self.diagram(code, lang.to_lowercase().as_str(), attrs);
+        }
+
         if self.options.is_highlight {
             let out = self.options.highlight(code, lang);
             if out != "" && out != code {
@@ -77,42 +180,163 @@ impl IRenderer for Renderer {
 
 
         if lang.len() == 0 {
-            return format!("<pre><code>{}</code></pre>\n",
+            return format!("<pre{}><code>{}</code></pre>\n",
+                           render_attrs(attrs),
                            if escaped { _code } else { escape(_code.as_str(), true) }
             );
         }
 
-        format!("<pre><code class=\"{}{}\">{}</code></pre>\n",
+        format!("<pre{}><code class=\"{}{}\">{}</code></pre>\n",
+                render_attrs(attrs),
                 self.options.lang_prefix,
                 escape(lang, true),
                 if escaped { _code } else { escape(_code.as_str(), true) }
         )
     }
 
+    fn diagram(&mut self, source: &str, lang: &str, attrs: &[(String, String)]) -> String {
+        if self.options.diagram_renderer.is_some() {
+            let rendered = (self.options.diagram_renderer.unwrap())(lang, source);
+            if rendered.is_some() {
+                return format!("{}\n", rendered.unwrap().trim_end());
+            }
+        }
+
+        // Diagram libraries read the element's text, so entities are decoded
+        let source = escape(regx_helper(RegexHelper::EndWithNewline).replace_all(source, "").as_ref(), false);
+
+        // Classes from the attribute list join the one the library looks for
+        let mut class = String::from(if lang == "mermaid" { "mermaid" } else { "diagram" });
+        let mut _attrs: Vec<(String, String)> = vec![];
+        for (key, value) in attrs.iter() {
+            if key == "class" {
+                class.push(' ');
+                class.push_str(value);
+            } else {
+                _attrs.push((key.clone(), value.clone()));
+            }
+        }
+
+        if lang == "mermaid" {
+            return format!("<pre class=\"{}\"{}>{}\n</pre>\n", escape(class.as_str(), false), render_attrs(&_attrs), source);
+        }
+
+        format!("<div class=\"{}\" data-lang=\"{}\"{}>{}\n</div>\n",
+                escape(class.as_str(), false),
+                escape(lang, true),
+                render_attrs(&_attrs),
+                source
+        )
+    }
+
     fn blockquote(&mut self, quote: &str) -> String {
         format!("<blockquote>\n{}</blockquote>\n", quote)
     }
 
+    fn alert(&mut self, body: &str, alert_type: &str) -> String {
+        let mut title = alert_type.chars();
+        let title = match title.next() {
+            Some(c) => format!("{}{}", c.to_uppercase(), title.as_str()),
+            None    => String::from("")
+        };
+
+        format!("<div class=\"markdown-alert markdown-alert-{}\">\n<p class=\"markdown-alert-title\">{}</p>\n{}</div>\n",
+                escape(alert_type, false),
+                escape(title.as_str(), false),
+                body
+        )
+    }
+
+    fn container(&mut self, body: &str, name: &str, args: &str) -> String {
+        let registered = self.options.container_renderers.iter()
+            .find(|(container_name, _)| *container_name == name);
+
+        if registered.is_some() {
+            let (_, render) = registered.unwrap();
+            return render(args, body);
+        }
+
+        if name == "details" {
+            let summary = if args.is_empty() { "Details" } else { args };
+            return format!("<details>\n<summary>{}</summary>\n{}</details>\n",
+                           escape(summary, false),
+                           body
+            );
+        }
+
+        let title = if args.is_empty() {
+            String::from("")
+        } else {
+            format!("<p class=\"container-title\">{}</p>\n", escape(args, false))
+        };
+
+        format!("<div class=\"{}\">\n{}{}</div>\n",
+                escape(name, false),
+                title,
+                body
+        )
+    }
+
     fn html(&mut self, html: &str) -> String {
-        String::from(html)
+        // Comments have a mode of their own
+        let mode = if html.trim_start().starts_with("<!--") {
+            self.options.html_comments
+        } else {
+            self.options.raw_html
+        };
+
+        match mode {
+            HtmlMode::Escape => return escape(html, true),
+            HtmlMode::Strip  => return String::new(),
+            HtmlMode::Allow  => {}
+        }
+
+        let html = if self.options.sanitize_html {
+            sanitize_html(html, &self.options)
+        } else {
+            String::from(html)
+        };
+
+        if self.options.gfm && self.options.tagfilter {
+            return filter_disallowed_tags(html.as_str());
+        }
+        html
     }
 
-    fn heading(&mut self, text: &str, level: usize, raw: &str, mut slugger: &mut Slugger) -> String {
-        if self.options.header_ids {
-            return format!("<h{} id=\"{}{}\">{}</h{}>\n",
+    fn heading(&mut self, text: &str, level: usize, raw: &str, mut slugger: &mut Slugger, attrs: &[(String, String)]) -> String {
+        let explicit_id = attrs.iter().find(|(k, _)| k == "id");
+        let _attrs: Vec<(String, String)> = attrs.iter()
+            .filter(|(k, _)| k != "id")
+            .cloned()
+            .collect();
+
+        let id = if explicit_id.is_some() {
+            // Explicit ids override the slugger but still reserve the slug
+            let id = explicit_id.unwrap().1.as_str();
+            slugger.register(id);
+            Some(id.to_string())
+        } else if self.options.header_ids {
+            Some(format!("{}{}", self.options.header_prefix, slugger.slug(raw, false)))
+        } else {
+            None
+        };
+
+        if id.is_none() {
+            // Ignore IDs
+            return format!("<h{}{}>{}</h{}>\n",
                            level,
-                           self.options.header_prefix,
-                           slugger.slug(raw, false),
+                           render_attrs(&_attrs),
                            text,
                            level
-
             )
         }
 
-        // Ignore IDs
-        format!("<h{}>{}</h{}>\n",
+        let id = id.unwrap();
+        format!("<h{} id=\"{}\"{}>{}</h{}>\n",
                 level,
-                text,
+                escape(id.as_str(), false),
+                render_attrs(&_attrs),
+                self.heading_anchor(text, id.as_str(), level),
                 level
         )
     }
@@ -144,10 +368,38 @@ impl IRenderer for Renderer {
         )
     }
 
+    fn task_checkbox(&mut self, checked: bool, attrs: &[(String, String)]) -> String {
+        format!(r#"<input {}{}type="checkbox"{}{}> "#,
+                if checked {r#"checked="" "#.to_string()} else {"".to_string()},
+                if self.options.task_list_interactive {"".to_string()} else {r#"disabled="" "#.to_string()},
+                render_attrs(attrs),
+                if self.options.xhtml {" /".to_string()} else {"".to_string()}
+        )
+    }
+
+    fn dl(&mut self, body: &str) -> String {
+        format!("<dl>\n{}</dl>\n", body)
+    }
+
+    fn dt(&mut self, text: &str) -> String {
+        format!("<dt>{}</dt>\n", text)
+    }
+
+    fn dd(&mut self, text: &str) -> String {
+        format!("<dd>{}</dd>\n", text)
+    }
+
     fn paragraph(&mut self, text: &str) -> String {
         format!("<p>{}</p>\n", text)
     }
 
+    fn figure(&mut self, image: &str, caption: &str) -> String {
+        if caption.is_empty() {
+            return format!("<figure>\n{}\n</figure>\n", image);
+        }
+        format!("<figure>\n{}\n<figcaption>{}</figcaption>\n</figure>\n", image, caption)
+    }
+
     fn table(&mut self, header: &str, body: &str) -> String {
         let mut _body = String::from(body);
         if _body != "" {
@@ -179,6 +431,34 @@ impl IRenderer for Renderer {
         )
     }
 
+    fn table_with_caption(&mut self, caption: &str, header: &str, body: &str) -> String {
+        let mut _body = String::from(body);
+        if _body != "" {
+            _body = format!("<tbody>{}</tbody>", body)
+        }
+
+        format!("<table>\n<caption>{}</caption>\n<thead>\n{}</thead>\n{}</table>\n",
+                caption,
+                header,
+                _body
+        )
+    }
+
+    fn tablecell_span(&mut self, content: &str, flags: Flags, colspan: usize) -> String {
+        let _type = if flags.header {"th".to_string()} else {"td".to_string()};
+        let tag = if flags.align != "" {
+            format!(r#"<{} colspan="{}" align="{}">"#, _type, colspan, flags.align)
+        } else {
+            format!(r#"<{} colspan="{}">"#, _type, colspan)
+        };
+
+        format!("{}{}</{}>\n",
+                tag,
+                content,
+                _type
+        )
+    }
+
 
     // Span Level Renderer
     fn strong(&mut self, text: &str) -> String {
@@ -201,7 +481,41 @@ impl IRenderer for Renderer {
         format!("<del>{}</del>", text)
     }
 
-    fn link(&mut self, href: &str, title: &str, text: &str) -> String {
+    fn mark(&mut self, text: &str) -> String {
+        format!("<mark>{}</mark>", text)
+    }
+
+    fn sup(&mut self, text: &str) -> String {
+        format!("<sup>{}</sup>", text)
+    }
+
+    fn sub(&mut self, text: &str) -> String {
+        format!("<sub>{}</sub>", text)
+    }
+
+    fn ins(&mut self, text: &str) -> String {
+        format!("<ins>{}</ins>", text)
+    }
+
+    fn emoji(&mut self, name: &str, unicode: &str) -> String {
+        if self.options.emoji_image_url.is_empty() {
+            return String::from(unicode);
+        }
+
+        let src = emoji::image_url(self.options.emoji_image_url, name, unicode);
+        format!(r#"<img class="emoji" title=":{}:" alt=":{}:" src="{}"{}"#,
+                name,
+                name,
+                escape(src.as_str(), false),
+                if self.options.xhtml { "/>" } else { ">" }
+        )
+    }
+
+    fn abbr(&mut self, text: &str, title: &str) -> String {
+        format!(r#"<abbr title="{}">{}</abbr>"#, escape(title, false), text)
+    }
+
+    fn link(&mut self, href: &str, title: &str, text: &str, attrs: &[(String, String)]) -> String {
         let __href = clean_url(self.options.sanitize, self.options.base_url, href);
 
         if __href.is_none()  {
@@ -215,11 +529,11 @@ impl IRenderer for Renderer {
             out = format!(r#"{} title="{}""#, out, title);
         }
 
-        out = format!("{}>{}</a>", out, text);
+        out = format!("{}{}>{}</a>", out, render_attrs(self.link_policy_attrs(href, attrs).as_slice()), text);
         out
     }
 
-    fn image(&mut self, href: &str, title: &str, text: &str) -> String {
+    fn image(&mut self, href: &str, title: &str, text: &str, attrs: &[(String, String)]) -> String {
         let __href = clean_url(self.options.sanitize, self.options.base_url, href);
 
         if __href.is_none()  {
@@ -233,6 +547,8 @@ impl IRenderer for Renderer {
             out = format!(r#"{} title="{}""#, out, title);
         }
 
+        out.push_str(render_attrs(self.image_attrs(href, attrs).as_slice()).as_str());
+
         out = if self.options.xhtml {
             format!("{}{}", out, "/>".to_string())
         } else {
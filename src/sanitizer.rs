@@ -0,0 +1,167 @@
+#![allow(warnings, unused)]
+use html5ever::tendril::StrTendril;
+use html5ever::tokenizer::states::RawKind;
+use html5ever::tokenizer::{
+    BufferQueue, CharacterTokens, EndTag, StartTag, Tag, TagToken, Token,
+    TokenSink, TokenSinkResult, Tokenizer, TokenizerOpts
+};
+
+use crate::defaults::Options;
+
+// Elements dropped together with their content
+const REMOVED_CONTENT: &[&str] = &[
+    "script", "style", "template", "iframe", "noembed", "noframes", "noscript",
+    "object", "title", "textarea", "xmp", "plaintext", "svg", "math"
+];
+
+/**
+ * Clean an HTML fragment against the allowlists of `options`. Elements not
+ * allowed are dropped but keep their text, except for scripts, styles and the
+ * like whose content goes too. Attributes not allowed, or holding a URL with a
+ * scheme not allowed for that attribute, are removed; comments and doctypes
+ * are removed as well. Enabled by `sanitize_html` and `sanitize_output`, it
+ * replaces the deprecated `sanitize` and `sanitizer` options
+ */
+pub fn sanitize_html(html: &str, options: &Options) -> String {
+    let sink = Sanitizer {
+        options: options.clone(),
+        out: String::with_capacity(html.len()),
+        removed: vec![]
+    };
+
+    let mut tokenizer = Tokenizer::new(sink, TokenizerOpts::default());
+    let mut input = BufferQueue::new();
+    input.push_back(StrTendril::from_slice(html));
+
+    let _ = tokenizer.feed(&mut input);
+    tokenizer.end();
+
+    tokenizer.sink.out
+}
+
+struct Sanitizer {
+    options: Options,
+    out: String,
+    // Open elements whose content is being removed
+    removed: Vec<String>
+}
+
+impl Sanitizer {
+    fn start_tag(&mut self, tag: &Tag) -> TokenSinkResult<()> {
+        let name = tag.name.to_string();
+
+        let allowed = self.options.sanitize_tags.contains(&name.as_str());
+
+        if !self.removed.is_empty() || !allowed {
+            if !allowed && !tag.self_closing && REMOVED_CONTENT.contains(&name.as_str()) {
+                self.removed.push(name.clone());
+            }
+            return raw_kind(name.as_str());
+        }
+
+        self.out.push('<');
+        self.out.push_str(name.as_str());
+
+        for attr in tag.attrs.iter() {
+            let attr_name = attr.name.local.to_string();
+            if !self.allows_attribute(name.as_str(), attr_name.as_str(), &attr.value) {
+                continue;
+            }
+
+            self.out.push_str(format!(" {}=\"{}\"", attr_name, escape_attribute(&attr.value)).as_str());
+        }
+
+        self.out.push_str(if tag.self_closing { " />" } else { ">" });
+        raw_kind(name.as_str())
+    }
+
+    fn end_tag(&mut self, tag: &Tag) {
+        let name = tag.name.to_string();
+
+        if !self.removed.is_empty() {
+            if self.removed.last() == Some(&name) {
+                self.removed.pop();
+            }
+            return;
+        }
+
+        if self.options.sanitize_tags.contains(&name.as_str()) {
+            self.out.push_str(format!("</{}>", name).as_str());
+        }
+    }
+
+    fn allows_attribute(&self, tag: &str, name: &str, value: &str) -> bool {
+        let allowed = self.options.sanitize_attributes.iter()
+            .any(|(t, attrs)| (*t == "*" || *t == tag) && attrs.contains(&name));
+
+        if !allowed {
+            return false;
+        }
+
+        match self.options.sanitize_url_schemes.iter().find(|(attr, _)| *attr == name) {
+            Some((_, schemes)) => match url_scheme(value) {
+                Some(scheme) => schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme.as_str())),
+                None         => true
+            },
+            None => true
+        }
+    }
+}
+
+impl TokenSink for Sanitizer {
+    type Handle = ();
+
+    fn process_token(&mut self, token: Token, _line_number: u64) -> TokenSinkResult<()> {
+        match token {
+            TagToken(tag) => match tag.kind {
+                StartTag => return self.start_tag(&tag),
+                EndTag   => self.end_tag(&tag)
+            },
+            CharacterTokens(text) => {
+                if self.removed.is_empty() {
+                    self.out.push_str(escape_text(&text).as_str());
+                }
+            }
+            _ => {}
+        }
+        TokenSinkResult::Continue
+    }
+}
+
+// Tokenize the content of raw text elements the way a browser would, so
+// markup inside a `<script>` is not mistaken for tags
+fn raw_kind(name: &str) -> TokenSinkResult<()> {
+    match name {
+        "script"                                        => TokenSinkResult::RawData(RawKind::ScriptData),
+        "style" | "iframe" | "noembed" | "noframes" | "xmp" => TokenSinkResult::RawData(RawKind::Rawtext),
+        "title" | "textarea"                            => TokenSinkResult::RawData(RawKind::Rcdata),
+        "plaintext"                                     => TokenSinkResult::Plaintext,
+        _                                               => TokenSinkResult::Continue
+    }
+}
+
+// Scheme of an absolute URL, ignoring the whitespace and control characters
+// browsers strip, so `java&#9;script:` is still seen as `javascript`
+fn url_scheme(url: &str) -> Option<String> {
+    let compact: String = url.chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .collect();
+
+    let colon = compact.find(':')?;
+    let scheme = &compact[..colon];
+
+    let valid = scheme.chars().next().map_or(false, |c| c.is_ascii_alphabetic()) &&
+        scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.');
+
+    if valid { Some(scheme.to_lowercase()) } else { None }
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn escape_attribute(value: &str) -> String {
+    escape_text(value).replace('"', "&quot;")
+}
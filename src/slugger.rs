@@ -53,6 +53,13 @@ impl Slugger {
         slug
     }
 
+    pub fn register(&mut self, id: &str) {
+        // Explicit ids are kept as-is, later slugs are suffixed around them
+        if !self.seen.contains_key(id) {
+            self.seen.insert(id.to_string(), 0);
+        }
+    }
+
     pub fn slug(&mut self, value: &str, dryrun: bool) -> String {
         let slug = Slugger::serialize(value);
         let next_slug = self.get_next_safe_slug(slug.as_str(), dryrun);
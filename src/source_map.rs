@@ -0,0 +1,162 @@
+#![allow(warnings, unused)]
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use crate::tokenizer::Token;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize
+}
+
+/**
+ * Maps positions in the text the lexer works on (CRLF line endings and tabs
+ * replaced) back to byte offsets in the original source
+ */
+pub struct SourceMap {
+    pub normalized: String,
+    offsets: Vec<usize>,
+    line_starts: Vec<usize>
+}
+
+impl SourceMap {
+    pub fn new(src: &str) -> Self {
+        let mut normalized = String::with_capacity(src.len());
+        let mut offsets: Vec<usize> = Vec::with_capacity(src.len() + 1);
+        let mut chars = src.char_indices().peekable();
+
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '\r' => {
+                    // A CRLF pair maps to its `\r`, so spans ending at the line
+                    // break leave it out
+                    if chars.peek().map_or(false, |(_, next)| *next == '\n') {
+                        chars.next();
+                    }
+                    normalized.push('\n');
+                    offsets.push(idx);
+                }
+                '\t' => {
+                    normalized.push_str("    ");
+                    offsets.extend([idx; 4]);
+                }
+                _ => {
+                    normalized.push(c);
+                    offsets.extend((0..c.len_utf8()).map(|i| idx + i));
+                }
+            }
+        }
+        offsets.push(src.len());
+
+        let mut line_starts = vec![0];
+        line_starts.extend(normalized.match_indices('\n').map(|(idx, _)| idx + 1));
+
+        Self {
+            normalized,
+            offsets,
+            line_starts
+        }
+    }
+
+    // Byte offset in the original source
+    pub fn offset(&self, pos: usize) -> usize {
+        self.offsets[pos]
+    }
+
+    // Span of `len` bytes of normalized text starting at `pos`; line and
+    // column are 1-based, the column counted in characters
+    pub fn span(&self, pos: usize, len: usize) -> Span {
+        let line_idx = match self.line_starts.binary_search(&pos) {
+            Ok(idx) => idx,
+            Err(idx) => idx - 1
+        };
+        let line_start = self.line_starts[line_idx];
+
+        Span {
+            start: self.offsets[pos],
+            end: self.offsets[pos + len],
+            line: line_idx + 1,
+            column: self.normalized[line_start..pos].chars().count() + 1
+        }
+    }
+
+    pub fn find(&self, needle: &str, from: usize, to: usize) -> Option<usize> {
+        if needle.is_empty() || from > to {
+            return None;
+        }
+        self.normalized[from..to].find(needle).map(|idx| from + idx)
+    }
+
+    // Like `find`, but the match must begin a line, optionally after container
+    // prefixes (indentation, blockquote markers)
+    pub fn find_block(&self, needle: &str, from: usize, to: usize) -> Option<usize> {
+        let mut search = from;
+
+        while let Some(pos) = self.find(needle, search, to) {
+            let line_start = self.normalized[..pos].rfind('\n').map_or(0, |i| i + 1);
+
+            if self.normalized[line_start..pos].chars().all(|c| c == ' ' || c == '>') {
+                return Some(pos);
+            }
+            search = pos + needle.chars().next().unwrap().len_utf8();
+        }
+        None
+    }
+
+    // Locate a token's raw text between `from` and `to`: the whole raw when it
+    // is a verbatim slice of the source, otherwise its first line
+    pub fn locate(&self, raw: &str, from: usize, to: usize, block: bool) -> Option<(usize, usize)> {
+        let first_line = raw.split('\n').next().unwrap_or("");
+        let trimmed = if block { raw.trim_start_matches(' ') } else { raw };
+        let find = |needle: &str| if block { self.find_block(needle, from, to) } else { self.find(needle, from, to) };
+
+        if let Some(pos) = find(trimmed) {
+            return Some((pos, trimmed.len()));
+        }
+
+        let first_line = if block { first_line.trim_start_matches(' ') } else { first_line };
+        find(first_line).map(|pos| (pos, first_line.len()))
+    }
+}
+
+// Position of every token in document order, children searched for within
+// their parent
+pub fn locate_tokens(map: &SourceMap, tokens: &Vec<Rc<RefCell<Token>>>, from: usize, to: usize, located: &mut Vec<(Rc<RefCell<Token>>, Span)>) {
+    let mut cursor = from;
+
+    for token in tokens.iter() {
+        let t = token.as_ref().borrow();
+        if t._type == "space" || t.raw.trim().is_empty() {
+            continue;
+        }
+
+        let raw = t.raw.trim_end_matches('\n');
+        let block = BLOCK_TYPES.contains(&t._type);
+
+        let (start, end) = match map.locate(raw, cursor, to, block) {
+            Some((pos, len)) => {
+                located.push((Rc::clone(token), map.span(pos, len)));
+
+                let verbatim = len == if block { raw.trim_start_matches(' ').len() } else { raw.len() };
+                cursor = pos + len;
+                (pos, if verbatim { pos + len } else { to })
+            }
+            None => (cursor, to)
+        };
+
+        locate_tokens(map, &t.tokens, start, end, located);
+        locate_tokens(map, &t.items, start, end, located);
+        locate_tokens(map, &t.header, start, end, located);
+        for row in t.rows.iter() {
+            locate_tokens(map, row, start, end, located);
+        }
+    }
+}
+
+const BLOCK_TYPES: &[&str] = &[
+    "code", "heading", "table", "hr", "blockquote", "alert", "list", "list_item",
+    "paragraph", "html", "dl", "container"
+];
@@ -0,0 +1,132 @@
+#![allow(warnings, unused)]
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use crate::lexer::{ILexer, Lexer};
+use crate::marked::collect_tokens;
+use crate::source_map::SourceMap;
+use crate::tokenizer::Token;
+use crate::defaults::{get_default_options, Options};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Task {
+    pub index: usize,
+    pub line: usize,
+    pub offset: usize,
+    pub checked: bool,
+    pub text: String
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TaskSummary {
+    pub done: usize,
+    pub total: usize
+}
+
+/**
+ * Task list items of `src` in document order. `offset` is the byte offset of
+ * the `[` of the checkbox in `src` and `line` is 1-based
+ */
+pub fn tasks(src: &str, opt: Option<Options>) -> Vec<Task> {
+    let options = if opt.is_some() { opt.unwrap() } else { get_default_options() };
+
+    let mut lexer = Lexer::new(options);
+    let tokens = lexer.lex(src);
+
+    locate_tasks(src, tokens)
+        .into_iter()
+        .map(|(_, task)| task)
+        .collect()
+}
+
+/**
+ * Flip the checkbox of the task at `index` between `[ ]` and `[x]`, leaving
+ * every other byte of `md` untouched
+ */
+pub fn toggle_task(md: &str, index: usize) -> String {
+    toggle_task_with_options(md, index, get_default_options())
+}
+
+// Like `toggle_task`, for documents rendered with other options, so `index`
+// matches their `data-task-index`
+pub fn toggle_task_with_options(md: &str, index: usize, options: Options) -> String {
+    let found = tasks(md, Some(options));
+    let task = found.get(index);
+
+    if task.is_none() {
+        return md.to_string();
+    }
+
+    let marker = task.unwrap().offset + 1;
+    let replacement = if task.unwrap().checked { " " } else { "x" };
+
+    format!("{}{}{}", &md[..marker], replacement, &md[marker + 1..])
+}
+
+pub fn task_summary(md: &str) -> TaskSummary {
+    task_summary_with_options(md, get_default_options())
+}
+
+pub fn task_summary_with_options(md: &str, options: Options) -> TaskSummary {
+    let found = tasks(md, Some(options));
+
+    TaskSummary {
+        done: found.iter().filter(|task| task.checked).count(),
+        total: found.len()
+    }
+}
+
+// Match each task list item token to its checkbox in the source. Each item
+// is searched for within its own top-level block, after the previous item
+pub fn locate_tasks(src: &str, tokens: &Vec<Rc<RefCell<Token>>>) -> Vec<(Rc<RefCell<Token>>, Task)> {
+    let map = SourceMap::new(src);
+    let len = map.normalized.len();
+
+    let mut found = vec![];
+    let mut block_cursor: usize = 0;
+
+    for token in tokens.iter() {
+        let raw = token.as_ref().borrow().raw.trim_start_matches(' ').to_string();
+
+        // Link definitions are not kept as tokens, so blocks need not be adjacent
+        let (block_start, block_end) = match map.find_block(raw.as_str(), block_cursor, len) {
+            Some(pos) => (pos, pos + raw.len()),
+            None      => (block_cursor, len)
+        };
+
+        let mut items = vec![];
+        collect_tokens(&vec![Rc::clone(token)], "list_item", &mut items);
+
+        let mut cursor = block_start;
+        for item in items.into_iter().filter(|item| item.as_ref().borrow().task) {
+            let raw = item.as_ref().borrow().raw.clone();
+            let first_line = raw.split('\n').next().unwrap_or("").trim_start().to_string();
+            let bracket = first_line.find('[');
+
+            if bracket.is_none() {
+                continue;
+            }
+
+            if let Some(pos) = map.find_block(first_line.as_str(), cursor, block_end) {
+                let text = item.as_ref().borrow().text.split('\n').next().unwrap_or("").trim().to_string();
+
+                let task = Task {
+                    index: found.len(),
+                    line: map.span(pos, 0).line,
+                    offset: map.offset(pos + bracket.unwrap()),
+                    checked: item.as_ref().borrow().checked,
+                    text
+                };
+                found.push((Rc::clone(&item), task));
+
+                cursor = pos + first_line.len();
+            }
+        }
+
+        if block_end < len {
+            block_cursor = block_end;
+        }
+    }
+
+    found
+}
@@ -24,6 +24,12 @@ pub trait ITextRenderer {
     fn em(&mut self, text: &str) -> String;
     fn codespan(&mut self, text: &str) -> String;
     fn del(&mut self, text: &str) -> String;
+    fn mark(&mut self, text: &str) -> String;
+    fn sup(&mut self, text: &str) -> String;
+    fn sub(&mut self, text: &str) -> String;
+    fn ins(&mut self, text: &str) -> String;
+    fn emoji(&mut self, name: &str, unicode: &str) -> String;
+    fn abbr(&mut self, text: &str, title: &str) -> String;
     fn html(&mut self, text: &str) -> String;
     fn text(&mut self, text: &str) -> String;
     fn link(&mut self, href: &str, title: &str, text: &str) -> String;
@@ -48,6 +54,30 @@ impl ITextRenderer for TextRenderer {
         String::from(text)
     }
 
+    fn mark(&mut self, text: &str) -> String {
+        String::from(text)
+    }
+
+    fn sup(&mut self, text: &str) -> String {
+        String::from(text)
+    }
+
+    fn sub(&mut self, text: &str) -> String {
+        String::from(text)
+    }
+
+    fn ins(&mut self, text: &str) -> String {
+        String::from(text)
+    }
+
+    fn emoji(&mut self, _name: &str, unicode: &str) -> String {
+        String::from(unicode)
+    }
+
+    fn abbr(&mut self, text: &str, _title: &str) -> String {
+        String::from(text)
+    }
+
     fn html(&mut self, text: &str) -> String {
         String::from(text)
     }
@@ -26,5 +26,6 @@ pub struct Token {
     pub align: Vec<String>,
     pub rows: Vec<Vec<Token>>,
     pub header: Vec<Token>,
-    pub code_block_style: String
+    pub code_block_style: String,
+    pub attrs: Vec<(String, String)>
 }
\ No newline at end of file
@@ -7,10 +7,12 @@ use std::cell::RefCell;
 use fancy_regex::Captures;
 use lazy_static::lazy_static;
 
-use crate::defaults::Options;
-use crate::lexer::{InlineToken, Lexer, regx};
+use crate::defaults::{HtmlMode, MangleStrategy, Options, SmartypantsLocale};
+use crate::emoji;
+use crate::slugger::Slugger;
+use crate::lexer::{InlineToken, Lexer, regx, reverse_email};
 use crate::rules::{exec_block, exec_block_regress, exec_inline, get_inline, get_rules, MDBlock, MDInline, Rules};
-use crate::helpers::{escape, find_closing_bracket, is_divisible, is_not_divisible, is_odd, rtrim, split_cells};
+use crate::helpers::{escape, find_closing_bracket, is_divisible, is_not_divisible, is_odd, rtrim, split_cells, split_cells_with_spans, strip_trailing_attributes, take_leading_attributes};
 use crate::regex::{RegexHelper, RegexHelperFc, regx_helper, regx_helper_fc};
 
 
@@ -39,7 +41,8 @@ pub struct Token {
     pub align: Vec<String>,
     pub rows: Vec<Vec<Rc<RefCell<Token>>>>,
     pub header: Vec<Rc<RefCell<Token>>>,
-    pub code_block_style: String
+    pub code_block_style: String,
+    pub attrs: Vec<(String, String)>
 }
 
 
@@ -50,6 +53,36 @@ pub struct Link {
     pub tag: String
 }
 
+// Link reference definition as written, duplicates included
+#[derive(Clone, PartialEq, Debug)]
+pub struct LinkDefinition {
+    pub raw: String,
+    pub label: String,
+    pub href: String,
+    pub title: String
+}
+
+// Reference-style link or image, whether or not its label is defined
+#[derive(Clone, PartialEq, Debug)]
+pub struct LinkReference {
+    pub raw: String,
+    pub label: String,
+    pub defined: bool
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Abbreviation {
+    pub abbr: String,
+    pub title: String
+}
+
+// Result of resolving a `[[target]]`; missing pages render with `class="new"`
+#[derive(Clone, PartialEq, Debug)]
+pub struct WikiLink {
+    pub href: String,
+    pub missing: bool
+}
+
 impl Token {
     pub fn new(mut self) -> Self {
         Self {
@@ -76,7 +109,8 @@ impl Token {
             align: vec![],
             rows: vec![],
             header: vec![],
-            code_block_style: "".to_string()
+            code_block_style: "".to_string(),
+            attrs: vec![]
         }
     }
 
@@ -117,24 +151,31 @@ pub trait ITokenizer {
     fn blockquote(&mut self, src: &str) -> Option<Token>;
     fn list(&mut self, src: &str) -> Option<Token>;
     fn html(&mut self, src: &str) -> Option<Token>;
+    fn abbreviation(&mut self, src: &str) -> Option<Token>;
     fn def(&mut self, src: &str) -> Option<Token>;
     fn table(&mut self, src: &str, tokens: &mut Vec<InlineToken>) -> Option<Token>;
     fn lheading(&mut self, src: &str) -> Option<Token>;
+    fn def_list(&mut self, src: &str) -> Option<Token>;
+    fn container(&mut self, src: &str) -> Option<Token>;
     fn paragraph(&mut self, src: &str) -> Option<Token>;
     fn text(&mut self, src: &str) -> Option<Token>;
 
     // Inline
     fn escape(&mut self, src: &str) -> Option<Token>;
     fn tag(&mut self, src: &str, in_link: &mut bool, in_raw_block: &mut bool) -> Option<Token>;
+    fn wiki_link(&mut self, src: &str) -> Option<Token>;
     fn link(&mut self, src: &str) -> Option<Token>;
     fn ref_link(&mut self, src: &str, links: &Vec<Link>) -> Option<Token>;
     fn em_strong(&mut self, src: &str, masked_src: &str, prev_char: &str) -> Option<Token>;
     fn code_span(&mut self, src: &str) -> Option<Token>;
     fn br(&mut self, src: &str) -> Option<Token>;
     fn del(&mut self, src: &str) -> Option<Token>;
-    fn autolink(&mut self, src: &str, mangle: fn(text: &str) -> String) -> Option<Token>;
-    fn url(&mut self, src: &str, mangle: fn(text: &str) -> String) -> Option<Token>;
-    fn inline_text(&mut self, src: &str, in_raw_block: bool, smartypants : fn(text: &str) -> String) -> Option<Token>;
+    fn em_extended(&mut self, src: &str, masked_src: &str, prev_char: &str) -> Option<Token>;
+    fn autolink(&mut self, src: &str, mangle: fn(text: &str, options: &Options) -> String) -> Option<Token>;
+    fn url(&mut self, src: &str, mangle: fn(text: &str, options: &Options) -> String) -> Option<Token>;
+    fn reference(&mut self, src: &str, before: &str) -> Option<Token>;
+    fn emoji(&mut self, src: &str) -> Option<Token>;
+    fn inline_text(&mut self, src: &str, in_raw_block: bool, smartypants : fn(text: &str, locale: SmartypantsLocale) -> String) -> Option<Token>;
 }
 
 type InlineTokenCallback = fn(&mut Lexer, src: String, tokens: Vec<Token>, parent_block_idx: usize);
@@ -156,6 +197,277 @@ impl Tokenizer {
     pub fn get_rules(self) -> Rules {
         self.rules
     }
+
+    // Raw text and label of the reference link or image at the start of `src`
+    pub fn ref_link_label(&self, src: &str) -> Option<(String, String)> {
+        let ref_link_caps = exec_inline(src, MDInline::RefLink, &self.options, "");
+        let caps = if ref_link_caps.is_some() {
+            ref_link_caps
+        } else {
+            exec_inline(src, MDInline::NoLink, &self.options, "")
+        };
+
+        caps.map(|caps| {
+            let cap1 = caps.get(1).map_or("", |m| m.as_str());
+            let cap2 = caps.get(2).map_or("", |m| m.as_str());
+
+            (
+                caps.get(0).map_or("", |m| m.as_str()).to_string(),
+                if cap2.len() > 0 { cap2.to_string() } else { cap1.to_string() }
+            )
+        })
+    }
+
+    // `![alt](src =WxH "title")`: an image with a size hint after its source,
+    // which the link rule alone rejects. Either dimension may be left out
+    pub fn sized_image(&mut self, src: &str) -> Option<Token> {
+        if !self.options.image_size_hints || !src.starts_with("![") {
+            return None;
+        }
+
+        // The hint follows the destination, so it is never looked for in the
+        // label or the title
+        let label_end = find_closing_bracket(&src[2..], "[]");
+        if label_end < 0 || !src[2 + label_end as usize..].starts_with("](") {
+            return None;
+        }
+
+        let mut dest = 2 + label_end as usize + 2;
+        dest = src.len() - src[dest..].trim_start_matches(|c| c == ' ' || c == '\t' || c == '\n').len();
+        dest = if src[dest..].starts_with('<') {
+            dest + src[dest..].find('>')? + 1
+        } else {
+            src[dest..].find(|c: char| c.is_ascii_whitespace()).map_or(src.len(), |idx| dest + idx)
+        };
+
+        let caps = regx_helper(RegexHelper::ImageSizeHint).captures(&src[dest..])?;
+        let hint = caps.get(1).unwrap();
+        let width = caps.get(2).map_or("", |m| m.as_str());
+        let height = caps.get(3).map_or("", |m| m.as_str());
+
+        if width.is_empty() && height.is_empty() {
+            return None;
+        }
+
+        let (hint_start, hint_end) = (dest + hint.start(), dest + hint.end());
+        let stripped = format!("{}{}", &src[..hint_start], &src[hint_end..]);
+
+        let mut token = self.link(stripped.as_str())?;
+        if token._type != "image" || token.raw.len() <= hint_start {
+            return None;
+        }
+
+        token.raw = src[..token.raw.len() + hint_end - hint_start].to_string();
+        for (key, value) in [("width", width), ("height", height)] {
+            if !value.is_empty() && !token.attrs.iter().any(|(k, _)| k == key) {
+                token.attrs.push((key.to_string(), value.to_string()));
+            }
+        }
+        Some(token)
+    }
+
+    // Earliest position after the first character where an opt-in inline
+    // rule may start, so inline text stops in front of it
+    pub fn inline_start(&self, src: &str) -> Option<usize> {
+        let mut starts: Vec<Option<usize>> = vec![];
+
+        if self.options.emoji {
+            let shortcode_re = regx_helper(RegexHelper::EmojiShortcode);
+            starts.push(src.match_indices(':')
+                .map(|(idx, _)| idx)
+                .find(|idx| {
+                    *idx > 0 && shortcode_re.captures(&src[*idx..])
+                        .map_or(false, |caps| emoji::lookup(&caps[1], &self.options).is_some())
+                }));
+        }
+
+        if self.options.mark || self.options.superscript || self.options.subscript || self.options.inserted {
+            // Openers after the last closing run of `==` or `++` cannot match,
+            // so they are skipped instead of each scanning to the end
+            let last_mark = last_closing_run(src, '=', 2);
+            let last_ins = last_closing_run(src, '+', 2);
+
+            starts.push(src.char_indices()
+                .filter(|(idx, c)| *idx > 0 && "=+^~".contains(*c))
+                .map(|(idx, _)| idx)
+                .filter(|idx| match &src[*idx..*idx + 1] {
+                    "=" => last_mark.map_or(false, |last| last > *idx + 2),
+                    "+" => last_ins.map_or(false, |last| last > *idx + 2),
+                    _ => true
+                })
+                .find(|idx| {
+                    let prev_char = src[..*idx].chars().last().map_or("".to_string(), |c| c.to_string());
+                    self.match_em_extended(&src[*idx..], &src[*idx..], prev_char.as_str()).is_some()
+                }));
+        }
+
+        if self.options.references {
+            // One scan for anything shaped like an issue, commit or mention;
+            // reference_link then checks the boundary and enabled kinds
+            starts.push(regx_helper(RegexHelper::ReferenceStart).find_iter(src)
+                .map(|m| m.start())
+                .find(|idx| *idx > 0 && self.reference_link(&src[*idx..], &src[..*idx]).is_some()));
+        }
+
+        starts.into_iter().flatten().min()
+    }
+
+    // `==mark==`, `++ins++`, `^sup^` and `~sub~`. Uses the same flanking checks
+    // as em_strong and the masked source to skip delimiters in code spans and
+    // links. Superscript and subscript may sit inside a word (`2^10^`, `H~2~O`)
+    // but cannot contain whitespace.
+    fn match_em_extended(&self, src: &str, masked_src: &str, prev_char: &str) -> Option<Token> {
+        let (delim, _type) = [
+            (self.options.mark, "==", "mark"),
+            (self.options.inserted, "++", "ins"),
+            (self.options.superscript, "^", "sup"),
+            (self.options.subscript, "~", "sub")
+        ].iter()
+            .find(|(enabled, delim, _)| *enabled && src.starts_with(delim))
+            .map(|(_, delim, _type)| (*delim, *_type))?;
+
+        let delim_char = delim.chars().next().unwrap();
+        let delim_len = delim.len();
+        let intraword = delim_len == 1;
+
+        // `masked_src` holds as many characters as `src`
+        let mut chars = src.char_indices().zip(masked_src.chars()).skip(delim_len);
+
+        // Left delimiter is a single run followed by non-whitespace
+        let mut before = match chars.next() {
+            Some(((_, c), _)) if !c.is_whitespace() && c != delim_char => c,
+            _ => return None
+        };
+
+        if !intraword && regx_helper(RegexHelper::LetterNumber).is_match(prev_char) {
+            return None;
+        }
+
+        // The right delimiter is a run of exactly the delimiter, unmasked and
+        // preceded by non-whitespace: (start, length, unmasked, preceding char)
+        let mut run: Option<(usize, usize, bool, char)> = None;
+        let mut closing: Option<(usize, usize)> = None;
+
+        for ((idx, c), masked) in chars {
+            if c == delim_char {
+                run = match run {
+                    Some((start, len, unmasked, run_before)) => Some((start, len + 1, unmasked && masked == delim_char, run_before)),
+                    None => Some((idx, 1, masked == delim_char, before))
+                };
+                continue;
+            }
+
+            if let Some((start, len, unmasked, run_before)) = run.take() {
+                if len == delim_len && unmasked && !run_before.is_whitespace() {
+                    closing = Some((start, idx));
+                    break;
+                }
+            }
+
+            if intraword && c.is_whitespace() {
+                return None;
+            }
+            before = c;
+        }
+
+        if closing.is_none() {
+            closing = run
+                .filter(|(_, len, unmasked, run_before)| *len == delim_len && *unmasked && !run_before.is_whitespace())
+                .map(|(start, _, _, _)| (start, src.len()));
+        }
+
+        let (close, end) = closing?;
+
+        Some(Token {
+            _type,
+            raw: src[..end].to_string(),
+            href: "".to_string(),
+            title: "".to_string(),
+            text: src[delim_len..close].to_string(),
+            tokens: vec![],
+            tag: "".to_string(),
+            ordered: false,
+            start: 0,
+            lang: "".to_string(),
+            loose: false,
+            items: vec![],
+            depth: 0,
+            escaped: false,
+            pre: false,
+            task: false,
+            checked: false,
+            in_link: false,
+            in_raw_block: false,
+            links: vec![],
+            align: vec![],
+            rows: vec![],
+            header: vec![],
+            code_block_style: "".to_string(),
+            attrs: vec![]
+        })
+    }
+
+    // `#123`, `org/repo#45`, commit SHAs and `@user`, only at the start of a word
+    fn reference_link(&self, src: &str, before: &str) -> Option<Token> {
+        let at_boundary = before.chars().last()
+            .map_or(true, |c| !(c.is_alphanumeric() || "_&/#@.-".contains(c)));
+
+        if !at_boundary {
+            return None;
+        }
+
+        if self.options.issue_url != "" {
+            if let Some(caps) = regx_helper(RegexHelper::ReferenceIssue).captures(src) {
+                let repo = caps.get(1).map_or(self.options.reference_repo, |m| m.as_str());
+                let href = expand_reference_url(self.options.issue_url, &[("{repo}", repo), ("{number}", &caps[2])]);
+
+                if let Some(href) = href {
+                    return Some(new_reference_link(&caps[0], href, &caps[0], "issue-link"));
+                }
+            }
+        }
+
+        if self.options.commit_url != "" {
+            if let Some(caps) = regx_helper(RegexHelper::ReferenceCommit).captures(src) {
+                let sha = &caps[2];
+                // Plain words and numbers made of hex digits are not SHAs
+                let is_sha = sha.chars().any(|c| c.is_ascii_digit()) && sha.chars().any(|c| c.is_ascii_alphabetic());
+                let repo = caps.get(1).map_or(self.options.reference_repo, |m| m.as_str());
+                let href = expand_reference_url(self.options.commit_url, &[("{repo}", repo), ("{sha}", sha)]);
+
+                if let (true, Some(href)) = (is_sha, href) {
+                    let text = match caps.get(1) {
+                        Some(repo) => format!("{}@{}", repo.as_str(), &sha[..7]),
+                        None => sha[..7].to_string()
+                    };
+                    return Some(new_reference_link(&caps[0], href, text.as_str(), "commit-link"));
+                }
+            }
+        }
+
+        if self.options.mention_url != "" {
+            if let Some(caps) = regx_helper(RegexHelper::ReferenceMention).captures(src) {
+                if let Some(href) = expand_reference_url(self.options.mention_url, &[("{user}", &caps[1])]) {
+                    return Some(new_reference_link(&caps[0], href, &caps[0], "user-mention"));
+                }
+            }
+        }
+
+        None
+    }
+
+    // Trailing `{...}` directly after a link or image
+    fn link_attributes(&self, src: &str, token: &mut Token) {
+        if token.raw.len() > src.len() {
+            return;
+        }
+
+        let rest = &src[token.raw.len()..];
+        if let Some((len, attrs)) = take_leading_attributes(rest, self.options.style_attributes) {
+            token.raw.push_str(&rest[..len]);
+            token.attrs = attrs;
+        }
+    }
 }
 
 impl ITokenizer for Tokenizer {
@@ -194,7 +506,8 @@ impl ITokenizer for Tokenizer {
                     align: vec![],
                     rows: vec![],
                     header: vec![],
-                    code_block_style: "".to_string()
+                    code_block_style: "".to_string(),
+                    attrs: vec![]
                 });
             }
         }
@@ -240,7 +553,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "indented".to_string()
+                code_block_style: "indented".to_string(),
+                attrs: vec![]
             });
         }
         None
@@ -261,7 +575,15 @@ impl ITokenizer for Tokenizer {
             let text = indent_code_compensation(raw, _text);
 
             let cap2 = caps.get(2);
-            let lang = if cap2.is_some() { cap2.map_or("", |m| m.as_str()).trim().to_string() } else { "".to_string() };
+            let mut lang = if cap2.is_some() { cap2.map_or("", |m| m.as_str()).trim().to_string() } else { "".to_string() };
+            let mut attrs = vec![];
+
+            if self.options.attributes {
+                if let Some((info, info_attrs)) = strip_trailing_attributes(lang.as_str(), self.options.style_attributes) {
+                    lang = info.trim().to_string();
+                    attrs = info_attrs;
+                }
+            }
 
             return Some(Token {
                 _type: "code",
@@ -287,7 +609,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs
             });
         }
         None
@@ -311,6 +634,14 @@ impl ITokenizer for Tokenizer {
                 }
             }
 
+            let mut attrs = vec![];
+            if self.options.attributes {
+                if let Some((heading_text, heading_attrs)) = strip_trailing_attributes(text.as_str(), self.options.style_attributes) {
+                    text = heading_text.trim().to_string();
+                    attrs = heading_attrs;
+                }
+            }
+
             let _raw = caps.get(0).map_or("", |m| m.as_str());
             let depth = caps.get(1).map_or("", |m| m.as_str()).len();
 
@@ -338,7 +669,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs
             };
 
             // self.lexer.inline(token.text, token.tokens);
@@ -380,7 +712,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             })
         }
         None
@@ -393,16 +726,35 @@ impl ITokenizer for Tokenizer {
         if blockquote_caps.is_some() {
             let caps = blockquote_caps.unwrap();
             let raw = caps.get(0).map_or("", |m| m.as_str());
-            let text  = regx_helper(RegexHelper::BlockQuoteText).replace_all(raw, "").to_string();
+            let mut text  = regx_helper(RegexHelper::BlockQuoteText).replace_all(raw, "").to_string();
+            let mut _type = "blockquote";
+            let mut tag = "".to_string();
+
+            // GitHub alerts: > [!NOTE], > [!WARNING], ...
+            if self.options.alerts {
+                let alert_caps = regx_helper(RegexHelper::AlertMarker).captures(text.as_str());
+
+                if alert_caps.is_some() {
+                    let caps = alert_caps.unwrap();
+                    let marker = caps.get(0).map_or("", |m| m.as_str()).to_string();
+                    let alert_type = caps.get(1).map_or("", |m| m.as_str()).to_lowercase();
+
+                    if self.options.alert_types.iter().any(|t| t.to_lowercase() == alert_type) {
+                        _type = "alert";
+                        tag = alert_type;
+                        text = slice(text.as_str(), marker.chars().count()..text.chars().count());
+                    }
+                }
+            }
 
             return Some(Token {
-                _type: "blockquote",
+                _type,
                 raw: raw.to_string(),
                 href: "".to_string(),
                 title: "".to_string(),
                 text,
                 tokens: vec![],
-                tag: "".to_string(),
+                tag,
                 ordered: false,
                 start: 0,
                 lang: "".to_string(),
@@ -419,7 +771,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             })
         }
         None
@@ -481,7 +834,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             };
 
             if is_ordered {
@@ -691,7 +1045,8 @@ impl ITokenizer for Tokenizer {
                             align: vec![],
                             rows: vec![],
                             header: vec![],
-                            code_block_style: "".to_string()
+                            code_block_style: "".to_string(),
+                            attrs: vec![]
                         }
                     ))
                 );
@@ -760,7 +1115,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             };
 
             if self.options.sanitize {
@@ -776,6 +1132,47 @@ impl ITokenizer for Tokenizer {
         None
     }
 
+    fn abbreviation(&mut self, src: &str) -> Option<Token> {
+        if !self.options.abbreviations {
+            return None;
+        }
+
+        let caps = regx_helper(RegexHelper::AbbreviationDef).captures(src)?;
+        let abbr = caps.get(1).map_or("", |m| m.as_str()).trim();
+
+        if abbr.is_empty() {
+            return None;
+        }
+
+        Some(Token {
+            _type: "abbr_def",
+            raw: caps.get(0).map_or("", |m| m.as_str()).to_string(),
+            href: "".to_string(),
+            title: caps.get(2).map_or("", |m| m.as_str()).to_string(),
+            text: "".to_string(),
+            tokens: vec![],
+            tag: abbr.to_string(),
+            ordered: false,
+            start: 0,
+            lang: "".to_string(),
+            loose: false,
+            items: vec![],
+            depth: 0,
+            escaped: false,
+            pre: false,
+            task: false,
+            checked: false,
+            in_link: false,
+            in_raw_block: false,
+            links: vec![],
+            align: vec![],
+            rows: vec![],
+            header: vec![],
+            code_block_style: "".to_string(),
+            attrs: vec![]
+        })
+    }
+
     fn def(&mut self, src: &str) -> Option<Token> {
         let def_caps = exec_block(src, MDBlock::Def, &self.options, "");
 
@@ -817,7 +1214,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             });
         }
         None
@@ -825,6 +1223,21 @@ impl ITokenizer for Tokenizer {
 
     fn table(&mut self, src: &str, mut tokens: &mut Vec<InlineToken>) -> Option<Token> {
 
+        // An extended table may start with a `Table: caption` or `[caption]` line
+        let mut caption = String::from("");
+        let mut caption_len = 0;
+        if self.options.table_extensions {
+            if let Some(caps) = regx_helper(RegexHelper::TableCaption).captures(src) {
+                let len = caps.get(0).unwrap().end();
+                if exec_block_regress(&src[len..], MDBlock::Table, &self.options, "").is_some() {
+                    caption = caps.get(1).or(caps.get(2)).map_or("", |m| m.as_str()).to_string();
+                    caption_len = len;
+                }
+            }
+        }
+        let full_src = src;
+        let src = &src[caption_len..];
+
         let table_caps = exec_block_regress(src, MDBlock::Table, &self.options, "");
         // let table_caps = exec_block(src, MDBlock::Table, &self.options, "");
 
@@ -842,36 +1255,14 @@ impl ITokenizer for Tokenizer {
             // let cap2 = caps.get(2).map_or("", |m| m.as_str());
             // let cap3 = caps.get(3).map_or("", |m| m.as_str());
 
-            let mut header = split_cells(cap1, None)
-                .into_iter()
-                .map(|header_val| {
-                    Rc::new(RefCell::new(Token {
-                        _type: "",
-                        raw: "".to_string(),
-                        href: "".to_string(),
-                        title: "".to_string(),
-                        text: header_val.to_string(),
-                        tokens: vec![],
-                        tag: "".to_string(),
-                        ordered: false,
-                        start: 0,
-                        lang: "".to_string(),
-                        loose: false,
-                        items: vec![],
-                        depth: 0,
-                        escaped: false,
-                        pre: false,
-                        task: false,
-                        checked: false,
-                        in_link: false,
-                        in_raw_block: false,
-                        links: vec![],
-                        align: vec![],
-                        rows: vec![],
-                        header: vec![],
-                        code_block_style: "".to_string()
-                    }))
-                })
+            let header_cells: Vec<(String, usize)> = if self.options.table_extensions {
+                split_cells_with_spans(cap1, None)
+            } else {
+                split_cells(cap1, None).into_iter().map(|text| (text, 1)).collect()
+            };
+
+            let mut header = header_cells.iter()
+                .map(|(text, colspan)| new_table_cell(text, *colspan))
                 .collect::<Vec<Rc<RefCell<Token>>>>();
 
 
@@ -883,7 +1274,7 @@ impl ITokenizer for Tokenizer {
                 .map(|x| x.to_string())
                 .collect();
 
-            let rows_= if cap3.trim() != "" {
+            let mut rows_: Vec<String> = if cap3.trim() != "" {
                 regx_helper(RegexHelper::TableRow)
                     .replace_all(cap3, "")
                     .split("\n")
@@ -893,7 +1284,26 @@ impl ITokenizer for Tokenizer {
                 vec![]
             };
 
-            if header.len() == align.len() {
+            let mut raw = format!("{}{}", &full_src[..caption_len], raw);
+            if self.options.table_extensions && caption.is_empty() {
+                // caption as the last line of the table
+                let last_caption = rows_.last()
+                    .and_then(|last| regx_helper(RegexHelper::TableCaption).captures(last.as_str()))
+                    .map(|caps| caps.get(1).or(caps.get(2)).map_or("", |m| m.as_str()).to_string());
+
+                if let Some(last_caption) = last_caption {
+                    caption = last_caption;
+                    rows_.pop();
+                } else if let Some(caps) = regx_helper(RegexHelper::TableCaption).captures(&full_src[raw.len()..]) {
+                    // or `Table: caption` after a blank line
+                    if let Some(text) = caps.get(1) {
+                        caption = text.as_str().to_string();
+                        raw.push_str(caps.get(0).unwrap().as_str());
+                    }
+                }
+            }
+
+            if header_cells.iter().map(|(_, colspan)| colspan).sum::<usize>() == align.len() {
 
                 let mut l = align.len();
                 for i in 0..l {
@@ -908,43 +1318,19 @@ impl ITokenizer for Tokenizer {
                     }
                 }
 
-                l = rows_.len();
+                let row_cells = if self.options.table_extensions {
+                    join_continued_rows(&rows_, align.len())
+                } else {
+                    rows_.iter()
+                        .map(|row| split_cells(row.as_str(), Some(align.len())).into_iter().map(|text| (text, 1)).collect())
+                        .collect::<Vec<Vec<(String, usize)>>>()
+                };
+
                 let mut rows: Vec<Vec<Rc<RefCell<Token>>>> = vec![];
-                for i in 0..l {
-                    let rows_i = split_cells(rows_[i].as_str(), Some(header.len()))
-                        .into_iter()
-                        .map(|text_val| {
-                            Rc::new(RefCell::new(
-                                Token {
-                                    _type: "",
-                                    raw: "".to_string(),
-                                    href: "".to_string(),
-                                    title: "".to_string(),
-                                    text: text_val.to_string(),
-                                    tokens: vec![],
-                                    tag: "".to_string(),
-                                    ordered: false,
-                                    start: 0,
-                                    lang: "".to_string(),
-                                    loose: false,
-                                    items: vec![],
-                                    depth: 0,
-                                    escaped: false,
-                                    pre: false,
-                                    task: false,
-                                    checked: false,
-                                    in_link: false,
-                                    in_raw_block: false,
-                                    links: vec![],
-                                    align: vec![],
-                                    rows: vec![],
-                                    header: vec![],
-                                    code_block_style: "".to_string()
-                                }
-                            ))
-                        })
-                        .collect::<Vec<Rc<RefCell<Token>>>>();
-                    rows.push(rows_i);
+                for cells in row_cells.iter() {
+                    rows.push(cells.iter()
+                        .map(|(text, colspan)| new_table_cell(text, *colspan))
+                        .collect::<Vec<Rc<RefCell<Token>>>>());
                 }
 
                 // parse child tokens inside headers and cells
@@ -963,10 +1349,10 @@ impl ITokenizer for Tokenizer {
 
                 let item = Token {
                     _type: "table",
-                    raw: raw.to_string(),
+                    raw,
                     href: "".to_string(),
                     title: "".to_string(),
-                    text: "".to_string(),
+                    text: caption,
                     tokens: vec![],
                     tag: "".to_string(),
                     ordered: false,
@@ -985,7 +1371,8 @@ impl ITokenizer for Tokenizer {
                     align,
                     rows,
                     header,
-                    code_block_style: "".to_string()
+                    code_block_style: "".to_string(),
+                    attrs: vec![]
                 };
                 return Some(item);
             }
@@ -1009,12 +1396,21 @@ impl ITokenizer for Tokenizer {
                 2 as usize
             };
 
+            let mut text = cap1.to_string();
+            let mut attrs = vec![];
+            if self.options.attributes {
+                if let Some((heading_text, heading_attrs)) = strip_trailing_attributes(cap1, self.options.style_attributes) {
+                    text = heading_text.trim().to_string();
+                    attrs = heading_attrs;
+                }
+            }
+
             let token = Token {
                 _type: "heading",
                 raw: raw.to_string(),
                 href: "".to_string(),
                 title: "".to_string(),
-                text: cap1.to_string(),
+                text,
                 tokens: vec![],
                 tag: "".to_string(),
                 ordered: false,
@@ -1033,7 +1429,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs
             };
 
             return Some(token);
@@ -1041,14 +1438,278 @@ impl ITokenizer for Tokenizer {
         None
     }
 
+    fn def_list(&mut self, src: &str) -> Option<Token> {
+
+        if !self.options.definition_lists {
+            return None;
+        }
+
+        let lines: Vec<&str> = src.split('\n').collect();
+        let l = lines.len();
+
+        let mut i: usize = 0;
+        let mut end: usize = 0;
+        let mut items: Vec<Rc<RefCell<Token>>> = vec![];
+
+        loop {
+            // Terms: one or more lines directly followed by a `: definition` line
+            let group_start = i;
+            let mut terms: Vec<&str> = vec![];
+            while i < l && is_def_list_term(lines[i]) {
+                terms.push(lines[i]);
+                i += 1;
+            }
+
+            let mut loose = false;
+            if i + 1 < l && lines[i].trim().is_empty() && regx_helper(RegexHelper::DefinitionMarker).is_match(lines[i + 1]) {
+                // Blank line between term and definition makes the definition loose
+                loose = true;
+                i += 1;
+            }
+
+            if terms.is_empty() || i >= l || !regx_helper(RegexHelper::DefinitionMarker).is_match(lines[i]) {
+                i = group_start;
+                break;
+            }
+
+            for term in terms.iter() {
+                items.push(Rc::new(RefCell::new(new_def_list_item("dt", term, term.trim(), false))));
+            }
+
+            // Definitions
+            while i < l && regx_helper(RegexHelper::DefinitionMarker).is_match(lines[i]) {
+                let dd_start = i;
+                let marker_len = regx_helper(RegexHelper::DefinitionMarker).find(lines[i]).unwrap().end();
+                let mut contents: Vec<String> = vec![lines[i][marker_len..].to_string()];
+                i += 1;
+
+                while i < l {
+                    let line = lines[i];
+
+                    if line.trim().is_empty() {
+                        // Blank lines only continue the definition when followed by indented content
+                        let mut j = i;
+                        while j < l && lines[j].trim().is_empty() { j += 1; }
+
+                        if j < l && get_indent(lines[j]) >= 4 {
+                            while i < j {
+                                contents.push("".to_string());
+                                i += 1;
+                            }
+                            loose = true;
+                            continue;
+                        }
+                        break;
+                    }
+
+                    if regx_helper(RegexHelper::DefinitionMarker).is_match(line) {
+                        break;
+                    }
+
+                    if get_indent(line) >= 4 {
+                        contents.push(line[4..].to_string());
+                    } else if i + 1 < l && regx_helper(RegexHelper::DefinitionMarker).is_match(lines[i + 1]) {
+                        // Line is the term of the next group
+                        break;
+                    } else {
+                        // Lazy continuation
+                        contents.push(line.trim_start().to_string());
+                    }
+                    i += 1;
+                }
+
+                let dd_raw = lines[dd_start..i].join("\n");
+                let dd_text = contents.join("\n");
+                items.push(Rc::new(RefCell::new(new_def_list_item("dd", dd_raw.as_str(), dd_text.trim_end(), loose))));
+                end = i;
+
+                // Blank lines between definitions of the same term
+                loose = false;
+                if i < l && lines[i].trim().is_empty() {
+                    let mut j = i;
+                    while j < l && lines[j].trim().is_empty() { j += 1; }
+
+                    if j < l && regx_helper(RegexHelper::DefinitionMarker).is_match(lines[j]) {
+                        loose = true;
+                        i = j;
+                    }
+                }
+            }
+
+            // Another group of terms may follow after blank lines
+            let mut j = i;
+            while j < l && lines[j].trim().is_empty() { j += 1; }
+            if j == i || j >= l || !is_def_list_term(lines[j]) {
+                break;
+            }
+            i = j;
+        }
+
+        if items.is_empty() {
+            return None;
+        }
+
+        let mut raw = lines[..end].join("\n");
+        if end < l {
+            raw.push('\n');
+        }
+
+        Some(Token {
+            _type: "dl",
+            raw,
+            href: "".to_string(),
+            title: "".to_string(),
+            text: "".to_string(),
+            tokens: vec![],
+            tag: "".to_string(),
+            ordered: false,
+            start: 0,
+            lang: "".to_string(),
+            loose: false,
+            items,
+            depth: 0,
+            escaped: false,
+            pre: false,
+            task: false,
+            checked: false,
+            in_link: false,
+            in_raw_block: false,
+            links: vec![],
+            align: vec![],
+            rows: vec![],
+            header: vec![],
+            code_block_style: "".to_string(),
+            attrs: vec![]
+        })
+    }
+
+    fn container(&mut self, src: &str) -> Option<Token> {
+
+        if !self.options.containers {
+            return None;
+        }
+
+        let lines: Vec<&str> = src.split('\n').collect();
+        let l = lines.len();
+
+        let open_caps = regx_helper(RegexHelper::ContainerOpen).captures(lines[0]);
+        if open_caps.is_none() {
+            return None;
+        }
+
+        let caps = open_caps.unwrap();
+        let marker_len = caps.get(1).map_or(0, |m| m.as_str().len());
+        let name = caps.get(2).map_or("", |m| m.as_str()).to_string();
+        let args = caps.get(3).map_or("", |m| m.as_str()).to_string();
+
+        // Containers opened with the same marker length nest; the closing
+        // marker must be at least as long as the opening one
+        let mut depth: usize = 0;
+        let mut fence: Option<String> = None;
+        let mut i: usize = 1;
+        let mut close: Option<usize> = None;
+
+        while i < l {
+            let line = lines[i];
+
+            // Markers inside fenced code belong to the code
+            let fence_marker = regx_helper(RegexHelper::ContainerCodeFence).captures(line)
+                .map(|caps| caps.get(1).map_or("", |m| m.as_str()).to_string());
+            if fence.is_some() {
+                let open_fence = fence.clone().unwrap();
+                if fence_marker.is_some() {
+                    let marker = fence_marker.unwrap();
+                    if marker[..1] == open_fence[..1] && marker.len() >= open_fence.len() && line.trim() == marker {
+                        fence = None;
+                    }
+                }
+                i += 1;
+                continue;
+            }
+            if fence_marker.is_some() {
+                fence = fence_marker;
+                i += 1;
+                continue;
+            }
+
+            let nested_caps = regx_helper(RegexHelper::ContainerOpen).captures(line);
+            if nested_caps.is_some() && nested_caps.unwrap().get(1).map_or(0, |m| m.as_str().len()) == marker_len {
+                depth += 1;
+            } else {
+                let close_caps = regx_helper(RegexHelper::ContainerClose).captures(line);
+                if close_caps.is_some() && close_caps.unwrap().get(1).map_or(0, |m| m.as_str().len()) >= marker_len {
+                    if depth == 0 {
+                        close = Some(i);
+                        break;
+                    }
+                    depth -= 1;
+                }
+            }
+            i += 1;
+        }
+
+        // An unclosed container runs to the end of the document
+        let end = close.unwrap_or(l);
+        let text = lines[1..end].join("\n");
+
+        let mut raw = if close.is_some() {
+            lines[..=end].join("\n")
+        } else {
+            lines.join("\n")
+        };
+        if end + 1 < l {
+            raw.push('\n');
+        }
+
+        Some(Token {
+            _type: "container",
+            raw,
+            href: "".to_string(),
+            title: args,
+            text,
+            tokens: vec![],
+            tag: name,
+            ordered: false,
+            start: 0,
+            lang: "".to_string(),
+            loose: false,
+            items: vec![],
+            depth: 0,
+            escaped: false,
+            pre: false,
+            task: false,
+            checked: false,
+            in_link: false,
+            in_raw_block: false,
+            links: vec![],
+            align: vec![],
+            rows: vec![],
+            header: vec![],
+            code_block_style: "".to_string(),
+            attrs: vec![]
+        })
+    }
+
     fn paragraph(&mut self, src: &str) -> Option<Token> {
 
         let paragraph_caps = exec_block(src, MDBlock::Paragraph, &self.options, "");
 
         if paragraph_caps.is_some() {
             let caps = paragraph_caps.unwrap();
-            let raw = caps.get(0).map_or("", |m| m.as_str());
-            let cap1 = caps.get(1).map_or("", |m| m.as_str());
+            let mut raw = caps.get(0).map_or("", |m| m.as_str());
+            let mut cap1 = caps.get(1).map_or("", |m| m.as_str());
+
+            // A `::: name` line starts a container without a blank line first
+            if self.options.containers {
+                let open = raw.match_indices('\n')
+                    .map(|(idx, _)| idx)
+                    .find(|idx| regx_helper(RegexHelper::ContainerOpen).is_match(raw[idx + 1..].split('\n').next().unwrap()));
+                if let Some(idx) = open {
+                    raw = &raw[..idx];
+                    cap1 = &cap1[..idx];
+                }
+            }
+
             let text = if cap1.chars().nth(cap1.len() - 1).is_some() &&
                 cap1.chars().nth(cap1.len() - 1).unwrap().to_string() == "\n"
             {
@@ -1082,7 +1743,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             };
             return Some(token);
         }
@@ -1121,7 +1783,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             };
             return Some(token);
         }
@@ -1163,7 +1826,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             });
         }
         None
@@ -1175,8 +1839,21 @@ impl ITokenizer for Tokenizer {
 
         if tag_caps.is_some() {
             let caps = tag_caps.unwrap();
-            let raw = caps.get(0).map_or("", |m| m.as_str());
+            let mut raw = caps.get(0).map_or("", |m| m.as_str());
 
+            // Stripping a script or style drops what it holds along with the
+            // tags, so the whole element up to its end tag becomes one token
+            let mut raw_text_element = false;
+            if self.options.raw_html == HtmlMode::Strip && !self.options.sanitize {
+                if let Some(open) = regx_helper(RegexHelper::RawTextTagStart).captures(raw) {
+                    let close = format!("</{}", open[1].to_ascii_lowercase());
+                    let end = src.to_ascii_lowercase()[raw.len()..].find(close.as_str())
+                        .and_then(|i| src[raw.len() + i..].find('>').map(|j| raw.len() + i + j + 1))
+                        .unwrap_or(src.len());
+                    raw = &src[..end];
+                    raw_text_element = true;
+                }
+            }
 
             if !*in_link && regx_helper(RegexHelper::AnchorTagStart).is_match(raw) {
                 *in_link = true;
@@ -1184,7 +1861,7 @@ impl ITokenizer for Tokenizer {
                 *in_link = false;
             }
 
-            if !*in_raw_block && regx_helper_fc(RegexHelperFc::RawBlockStart).is_match(raw).unwrap() {
+            if !raw_text_element && !*in_raw_block && regx_helper_fc(RegexHelperFc::RawBlockStart).is_match(raw).unwrap() {
                 *in_raw_block = true;
             } else if *in_raw_block && regx_helper_fc(RegexHelperFc::RawBlockEnd).is_match(raw).unwrap() {
                 *in_raw_block = false;
@@ -1229,12 +1906,102 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             });
         }
         None
     }
 
+    fn wiki_link(&mut self, src: &str) -> Option<Token> {
+        if !self.options.wiki_links {
+            return None;
+        }
+
+        let caps = regx_helper(RegexHelper::WikiLink).captures(src)?;
+        let inner = caps.get(1).map_or("", |m| m.as_str());
+        let (target, section) = match inner.find('#') {
+            Some(idx) => (inner[..idx].trim(), inner[idx + 1..].trim()),
+            None => (inner.trim(), "")
+        };
+
+        if target.is_empty() && section.is_empty() {
+            return None;
+        }
+
+        let resolved = if target.is_empty() {
+            WikiLink { href: "".to_string(), missing: false }
+        } else if let Some(resolver) = self.options.wiki_link_resolver {
+            resolver(target)
+        } else {
+            WikiLink { href: Slugger::serialize(target), missing: false }
+        };
+
+        let mut href = resolved.href;
+        if !section.is_empty() {
+            href = format!("{}#{}", href, Slugger::serialize(section));
+        }
+
+        let label = caps.get(2).map_or(inner, |m| m.as_str()).trim();
+        let text = escape(label, false);
+
+        Some(Token {
+            _type: "wikilink",
+            raw: caps.get(0).map_or("", |m| m.as_str()).to_string(),
+            href,
+            title: "".to_string(),
+            text: text.clone(),
+            tokens: vec![
+                Rc::new(RefCell::new(Token {
+                    _type: "text",
+                    raw: label.to_string(),
+                    href: "".to_string(),
+                    title: "".to_string(),
+                    text,
+                    tokens: vec![],
+                    tag: "".to_string(),
+                    ordered: false,
+                    start: 0,
+                    lang: "".to_string(),
+                    loose: false,
+                    items: vec![],
+                    depth: 0,
+                    escaped: false,
+                    pre: false,
+                    task: false,
+                    checked: false,
+                    in_link: false,
+                    in_raw_block: false,
+                    links: vec![],
+                    align: vec![],
+                    rows: vec![],
+                    header: vec![],
+                    code_block_style: "".to_string(),
+                    attrs: vec![]
+                }))
+            ],
+            tag: target.to_string(),
+            ordered: false,
+            start: 0,
+            lang: "".to_string(),
+            loose: false,
+            items: vec![],
+            depth: 0,
+            escaped: false,
+            pre: false,
+            task: false,
+            checked: false,
+            in_link: false,
+            in_raw_block: false,
+            links: vec![],
+            align: vec![],
+            rows: vec![],
+            header: vec![],
+            code_block_style: "".to_string(),
+            attrs: if resolved.missing { vec![("class".to_string(), "new".to_string())] } else { vec![] }
+        })
+    }
+
     fn link(&mut self, src: &str) -> Option<Token> {
 
         let link_caps = exec_inline(src, MDInline::Link, &self.options, "");
@@ -1340,7 +2107,10 @@ impl ITokenizer for Tokenizer {
                 tag: "".to_string()
             };
 
-            let token = output_link(caps, link, raw.to_string());
+            let mut token = output_link(caps, link, raw.to_string());
+            if self.options.attributes {
+                self.link_attributes(src, &mut token);
+            }
             return Some(token);
             // Set inline tokens for token.tokens see output_link (original)
         }
@@ -1399,7 +2169,8 @@ impl ITokenizer for Tokenizer {
                     align: vec![],
                     rows: vec![],
                     header: vec![],
-                    code_block_style: "".to_string()
+                    code_block_style: "".to_string(),
+                    attrs: vec![]
                 });
             }
 
@@ -1432,7 +2203,8 @@ impl ITokenizer for Tokenizer {
                     align: vec![],
                     rows: vec![],
                     header: vec![],
-                    code_block_style: "".to_string()
+                    code_block_style: "".to_string(),
+                    attrs: vec![]
                 });
             }
 
@@ -1442,7 +2214,10 @@ impl ITokenizer for Tokenizer {
                 tag: link_ref.tag.clone()
             };
 
-            let token = output_link(caps, link, raw.to_string());
+            let mut token = output_link(caps, link, raw.to_string());
+            if self.options.attributes {
+                self.link_attributes(src, &mut token);
+            }
             return Some(token);
         }
         None
@@ -1614,7 +2389,8 @@ impl ITokenizer for Tokenizer {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     });
                 }
 
@@ -1650,7 +2426,8 @@ impl ITokenizer for Tokenizer {
                     align: vec![],
                     rows: vec![],
                     header: vec![],
-                    code_block_style: "".to_string()
+                    code_block_style: "".to_string(),
+                    attrs: vec![]
                 });
             }
             // println!("====================================================================================");
@@ -1705,7 +2482,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             });
         }
         None
@@ -1743,7 +2521,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             };
 
             return Some(token);
@@ -1762,6 +2541,11 @@ impl ITokenizer for Tokenizer {
             let raw = caps.get(0).map_or("", |m| m.as_str());
             let caps_2 = caps.get(2).map_or("", |m| m.as_str());
 
+            // single tildes are subscript when that syntax is enabled
+            if self.options.subscript && caps.get(1).map_or("", |m| m.as_str()) == "~" {
+                return None;
+            }
+
             let token = Token {
                 _type: "del",
                 raw: raw.to_string(),
@@ -1786,7 +2570,8 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             };
 
             return Some(token);
@@ -1794,7 +2579,18 @@ impl ITokenizer for Tokenizer {
         None
     }
 
-    fn autolink(&mut self, src: &str, mangle: fn(text: &str) -> String) -> Option<Token> {
+    fn em_extended(&mut self, src: &str, masked_src: &str, prev_char: &str) -> Option<Token> {
+        // The masked source covers the whole inline text; line its end up with `src`
+        let count = src.chars().count();
+        let masked = match count.checked_sub(1).and_then(|n| masked_src.char_indices().rev().nth(n)) {
+            Some((idx, _)) => &masked_src[idx..],
+            None => src
+        };
+
+        self.match_em_extended(src, masked, prev_char)
+    }
+
+    fn autolink(&mut self, src: &str, mangle: fn(text: &str, options: &Options) -> String) -> Option<Token> {
 
         let autolink_caps = exec_inline(src, MDInline::Autolink, &self.options, "");
 
@@ -1809,11 +2605,15 @@ impl ITokenizer for Tokenizer {
 
             if cap2 == "@" {
                 text = if self.options.mangle {
-                    escape(mangle(cap1).as_str(), false)
+                    escape(mangle(cap1, &self.options).as_str(), false)
                 } else {
                     cap1.to_string()
                 };
-                href = format!("mailto:{}", text);
+                // Mangled by the parser once the URL rewriter has seen it
+                href = format!("mailto:{}", cap1);
+                if self.options.mangle && self.options.mangle_strategy == MangleStrategy::Css {
+                    text = reverse_email(cap1);
+                }
             } else {
                 text = escape(cap1, false);
                 href = text.clone();
@@ -1850,7 +2650,8 @@ impl ITokenizer for Tokenizer {
                             align: vec![],
                             rows: vec![],
                             header: vec![],
-                            code_block_style: "".to_string()
+                            code_block_style: "".to_string(),
+                            attrs: vec![]
                         }
                     ))
                 ],
@@ -1871,13 +2672,14 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             });
         }
         None
     }
 
-    fn url(&mut self, src: &str, mangle: fn(text: &str) -> String) -> Option<Token> {
+    fn url(&mut self, src: &str, mangle: fn(text: &str, options: &Options) -> String) -> Option<Token> {
 
         // let url_re_str =  self.rules.inline.url.as_str();
         // let url_re = regress::Regex::with_flags(url_re_str, "i").unwrap();
@@ -1900,11 +2702,15 @@ impl ITokenizer for Tokenizer {
 
             if cap2 == "@" {
                 text = if self.options.mangle {
-                    escape(mangle(raw).as_str(), false)
+                    escape(mangle(raw, &self.options).as_str(), false)
                 } else {
                     raw.to_string()
                 };
-                href = format!("mailto:{}", text);
+                // Mangled by the parser once the URL rewriter has seen it
+                href = format!("mailto:{}", raw);
+                if self.options.mangle && self.options.mangle_strategy == MangleStrategy::Css {
+                    text = reverse_email(raw);
+                }
             } else {
                 // do extended autolink path validation
                 let mut prev_cap_zero = raw.to_string().clone();
@@ -1959,7 +2765,8 @@ impl ITokenizer for Tokenizer {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }))
                 ],
                 tag: "".to_string(),
@@ -1979,13 +2786,60 @@ impl ITokenizer for Tokenizer {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             });
         }
         None
     }
 
-    fn inline_text(&mut self, src: &str, in_raw_block: bool, smartypants: fn(&str) -> String) -> Option<Token> {
+    fn reference(&mut self, src: &str, before: &str) -> Option<Token> {
+        if !self.options.references {
+            return None;
+        }
+
+        self.reference_link(src, before)
+    }
+
+    fn emoji(&mut self, src: &str) -> Option<Token> {
+        if !self.options.emoji {
+            return None;
+        }
+
+        let caps = regx_helper(RegexHelper::EmojiShortcode).captures(src)?;
+        let name = caps.get(1).map_or("", |m| m.as_str());
+        let unicode = emoji::lookup(name, &self.options)?;
+
+        Some(Token {
+            _type: "emoji",
+            raw: caps.get(0).map_or("", |m| m.as_str()).to_string(),
+            href: "".to_string(),
+            title: "".to_string(),
+            text: unicode.to_string(),
+            tokens: vec![],
+            tag: name.to_string(),
+            ordered: false,
+            start: 0,
+            lang: "".to_string(),
+            loose: false,
+            items: vec![],
+            depth: 0,
+            escaped: false,
+            pre: false,
+            task: false,
+            checked: false,
+            in_link: false,
+            in_raw_block: false,
+            links: vec![],
+            align: vec![],
+            rows: vec![],
+            header: vec![],
+            code_block_style: "".to_string(),
+            attrs: vec![]
+        })
+    }
+
+    fn inline_text(&mut self, src: &str, in_raw_block: bool, smartypants: fn(&str, SmartypantsLocale) -> String) -> Option<Token> {
 
         let inline_caps = exec_inline(src, MDInline::Text, &self.options, "");
 
@@ -2002,12 +2856,15 @@ impl ITokenizer for Tokenizer {
                     } else {
                         escape(raw, false)
                     }
+                } else if self.options.sanitize_html || self.options.raw_html != HtmlMode::Allow {
+                    // Text after an inline `<script>` is not markup either
+                    escape(raw, false)
                 } else {
                     raw.to_string()
                 }
             } else {
                 let html = if self.options.smartypants {
-                    smartypants(raw)
+                    smartypants(raw, self.options.smartypants_locale)
                 } else {
                     raw.to_string()
                 };
@@ -2041,7 +2898,8 @@ impl ITokenizer for Tokenizer {
                     align: vec![],
                     rows: vec![],
                     header: vec![],
-                    code_block_style: "".to_string()
+                    code_block_style: "".to_string(),
+                    attrs: vec![]
                 };
 
             return Some(token);
@@ -2089,7 +2947,8 @@ pub fn output_link(caps: Captures, link: Link, raw: String) -> Token {
             align: vec![],
             rows: vec![],
             header: vec![],
-            code_block_style: "".to_string()
+            code_block_style: "".to_string(),
+            attrs: vec![]
         };
         token
     } else {
@@ -2117,11 +2976,154 @@ pub fn output_link(caps: Captures, link: Link, raw: String) -> Token {
             align: vec![],
             rows: vec![],
             header: vec![],
-            code_block_style: "".to_string()
+            code_block_style: "".to_string(),
+            attrs: vec![]
         }
     }
 }
 
+fn new_reference_link(raw: &str, href: String, text: &str, class: &str) -> Token {
+    let text = escape(text, false);
+
+    Token {
+        _type: "link",
+        raw: raw.to_string(),
+        href,
+        title: "".to_string(),
+        text: text.clone(),
+        tokens: vec![
+            Rc::new(RefCell::new(Token {
+                _type: "text",
+                raw: text.clone(),
+                href: "".to_string(),
+                title: "".to_string(),
+                text,
+                tokens: vec![],
+                tag: "".to_string(),
+                ordered: false,
+                start: 0,
+                lang: "".to_string(),
+                loose: false,
+                items: vec![],
+                depth: 0,
+                escaped: false,
+                pre: false,
+                task: false,
+                checked: false,
+                in_link: false,
+                in_raw_block: false,
+                links: vec![],
+                align: vec![],
+                rows: vec![],
+                header: vec![],
+                code_block_style: "".to_string(),
+                attrs: vec![]
+            }))
+        ],
+        tag: "".to_string(),
+        ordered: false,
+        start: 0,
+        lang: "".to_string(),
+        loose: false,
+        items: vec![],
+        depth: 0,
+        escaped: false,
+        pre: false,
+        task: false,
+        checked: false,
+        in_link: false,
+        in_raw_block: false,
+        links: vec![],
+        align: vec![],
+        rows: vec![],
+        header: vec![],
+        code_block_style: "".to_string(),
+        attrs: vec![("class".to_string(), class.to_string())]
+    }
+}
+
+// Fills `{placeholder}`s; a placeholder without a value disables the template
+fn expand_reference_url(template: &str, values: &[(&str, &str)]) -> Option<String> {
+    let mut url = template.to_string();
+
+    for (placeholder, value) in values {
+        if url.contains(placeholder) {
+            if value.is_empty() {
+                return None;
+            }
+            url = url.replace(placeholder, value);
+        }
+    }
+
+    Some(url)
+}
+
+fn new_table_cell(text: &str, colspan: usize) -> Rc<RefCell<Token>> {
+    Rc::new(RefCell::new(Token {
+        _type: "",
+        raw: "".to_string(),
+        href: "".to_string(),
+        title: "".to_string(),
+        text: text.to_string(),
+        tokens: vec![],
+        tag: "".to_string(),
+        ordered: false,
+        start: 0,
+        lang: "".to_string(),
+        loose: false,
+        items: vec![],
+        depth: 0,
+        escaped: false,
+        pre: false,
+        task: false,
+        checked: false,
+        in_link: false,
+        in_raw_block: false,
+        links: vec![],
+        align: vec![],
+        rows: vec![],
+        header: vec![],
+        code_block_style: "".to_string(),
+        attrs: if colspan > 1 { vec![("colspan".to_string(), colspan.to_string())] } else { vec![] }
+    }))
+}
+
+// Rows ending in a backslash continue on the next line; continued cells are
+// joined with a hard line break
+fn join_continued_rows(lines: &Vec<String>, count: usize) -> Vec<Vec<(String, usize)>> {
+    let mut rows = vec![];
+    let mut i = 0;
+
+    while i < lines.len() {
+        let mut line = lines[i].trim_end().to_string();
+        let mut continued = line.ends_with('\\');
+        if continued {
+            line.pop();
+        }
+
+        let mut cells = split_cells_with_spans(line.as_str(), Some(count));
+        while continued && i + 1 < lines.len() {
+            i += 1;
+            let mut next = lines[i].trim_end().to_string();
+            continued = next.ends_with('\\');
+            if continued {
+                next.pop();
+            }
+
+            for (k, (text, _)) in split_cells_with_spans(next.as_str(), Some(count)).into_iter().enumerate() {
+                if k >= cells.len() || text.is_empty() {
+                    continue;
+                }
+                cells[k].0 = if cells[k].0.is_empty() { text } else { format!("{}\\\n{}", cells[k].0, text) };
+            }
+        }
+
+        rows.push(cells);
+        i += 1;
+    }
+    rows
+}
+
 pub fn indent_code_compensation(raw: &str, text: String) -> String {
 
     let indent_to_code_caps = regx_helper(RegexHelper::IndentToCode).captures(raw);
@@ -2156,6 +3158,46 @@ pub fn indent_code_compensation(raw: &str, text: String) -> String {
         .join("\n");
 }
 
+fn new_def_list_item(_type: &'static str, raw: &str, text: &str, loose: bool) -> Token {
+    Token {
+        _type,
+        raw: raw.to_string(),
+        href: "".to_string(),
+        title: "".to_string(),
+        text: text.to_string(),
+        tokens: vec![],
+        tag: "".to_string(),
+        ordered: false,
+        start: 0,
+        lang: "".to_string(),
+        loose,
+        items: vec![],
+        depth: 0,
+        escaped: false,
+        pre: false,
+        task: false,
+        checked: false,
+        in_link: false,
+        in_raw_block: false,
+        links: vec![],
+        align: vec![],
+        rows: vec![],
+        header: vec![],
+        code_block_style: "".to_string(),
+        attrs: vec![]
+    }
+}
+
+fn is_def_list_term(line: &str) -> bool {
+    !line.trim().is_empty() &&
+        get_indent(line) < 4 &&
+        !regx_helper(RegexHelper::DefinitionMarker).is_match(line)
+}
+
+fn get_indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
 pub fn slice(s: &str, range: Range<usize>) -> String {
     if s.len() > range.start && s.len() >= range.end {
         s.chars().take(range.end).skip(range.start).collect()
@@ -2164,6 +3206,28 @@ pub fn slice(s: &str, range: Range<usize>) -> String {
     }
 }
 
+// Byte offset of the last run of exactly `len` delimiters that follows
+// non-whitespace, the last place a `==mark==` or `++ins++` can close
+fn last_closing_run(src: &str, delim: char, len: usize) -> Option<usize> {
+    let mut last = None;
+    let mut run: Option<(usize, usize)> = None;
+    let mut before = ' ';
+
+    for (idx, c) in src.char_indices().chain(std::iter::once((src.len(), ' '))) {
+        if c == delim {
+            run = Some(run.map_or((idx, 1), |(start, n)| (start, n + 1)));
+            continue;
+        }
+        if let Some((start, n)) = run.take() {
+            if n == len && !before.is_whitespace() {
+                last = Some(start);
+            }
+        }
+        before = c;
+    }
+    last
+}
+
 fn slice_from_end(s: &str, n: usize) -> String {
     let slice_rev = s.char_indices().rev().nth(n).map(|(i, _)| &s[i..]);
     if slice_rev.is_some() {
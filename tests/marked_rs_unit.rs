@@ -8,11 +8,18 @@ use pretty_assertions::{assert_eq, assert_ne};
 mod marked {
     use std::panic;
     use marked_rs::slugger::Slugger;
-    use marked_rs::defaults::get_default_options;
+    use marked_rs::defaults::{get_base_options, get_default_options, HtmlMode, MangleStrategy, HeadingAnchors, SmartypantsLocale, UrlKind};
     use marked_rs::lexer::{ILexer, Lexer};
     use marked_rs::marked::Marked;
     use marked_rs::renderer::{IRenderer, Renderer};
     use marked_rs::rules::test;
+    use marked_rs::tokenizer::{Abbreviation, Token, WikiLink};
+    use marked_rs::tasks::{tasks, task_summary, task_summary_with_options, toggle_task, toggle_task_with_options, TaskSummary};
+    use marked_rs::link_references::{inspect_references, normalize_label, ReferenceKind};
+    use marked_rs::source_map::Span;
+    use marked_rs::lint::{get_default_lint_options, lint, Rule, Severity};
+    use marked_rs::anchors::{anchor_ids, check_documents, check_files, Problem};
+    use std::sync::Mutex;
     use super::*;
 
     #[test]
@@ -23,7 +30,7 @@ mod marked {
         let mut renderer = Renderer::new(options);
 
         let expected_header = "<h1 id=\"test\">test</h1>\n";
-        let actual_header = renderer.heading("test", 1, "test", &mut slugger);
+        let actual_header = renderer.heading("test", 1, "test", &mut slugger, &[]);
 
         pretty_assertions::assert_eq!(actual_header, expected_header);
     }
@@ -38,7 +45,7 @@ mod marked {
         let mut renderer = Renderer::new(options);
 
         let expected_header = "<h1>test</h1>\n";
-        let actual_header = renderer.heading("test", 1, "test", &mut slugger);
+        let actual_header = renderer.heading("test", 1, "test", &mut slugger, &[]);
 
         pretty_assertions::assert_eq!(actual_header, expected_header);
     }
@@ -178,5 +185,1226 @@ mod marked {
 
         pretty_assertions::assert_eq!(actual_html, "# header\n\n<em>em</em>");
     }
-}
 
+    #[test]
+    fn should_render_github_alerts() {
+        let mut options = get_default_options();
+        options.alerts = true;
+
+        let md = "> [!NOTE]\n> Useful information.\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\">Note</p>\n<p>Useful information.</p>\n</div>\n");
+    }
+
+    #[test]
+    fn should_only_render_configured_alert_types() {
+        let mut options = get_default_options();
+        options.alerts = true;
+        options.alert_types = &["warning"];
+
+        let mut marked = Marked::new(None);
+        let note_html = marked.parse("> [!NOTE]\n> text\n", Some(options), None);
+        let warning_html = marked.parse("> [!Warning]\n> text\n", Some(options), None);
+
+        pretty_assertions::assert_eq!(note_html, "<blockquote>\n<p>[!NOTE]\ntext</p>\n</blockquote>\n");
+        pretty_assertions::assert_eq!(warning_html, "<div class=\"markdown-alert markdown-alert-warning\">\n<p class=\"markdown-alert-title\">Warning</p>\n<p>text</p>\n</div>\n");
+    }
+
+    #[test]
+    fn should_require_alert_marker_on_its_own_line() {
+        let mut options = get_default_options();
+        options.alerts = true;
+
+        // GitHub takes no custom titles after the marker either
+        let actual_html = Marked::new(None).parse("> [!NOTE] Custom title\n> text\n", Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<blockquote>\n<p>[!NOTE] Custom title\ntext</p>\n</blockquote>\n");
+    }
+
+    #[test]
+    fn should_not_render_alerts_by_default() {
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse("> [!TIP]\n> text\n", None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<blockquote>\n<p>[!TIP]\ntext</p>\n</blockquote>\n");
+    }
+
+    #[test]
+    fn should_render_definition_lists() {
+        let mut options = get_default_options();
+        options.definition_lists = true;
+
+        let md = "Apple\nPomme\n:   Pomaceous fruit\n:   A company\n\nOrange\n:   Citrus fruit\nwith lazy continuation\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<dl>\n<dt>Apple</dt>\n<dt>Pomme</dt>\n<dd>Pomaceous fruit</dd>\n<dd>A company</dd>\n<dt>Orange</dt>\n<dd>Citrus fruit\nwith lazy continuation</dd>\n</dl>\n");
+    }
+
+    #[test]
+    fn should_render_block_content_in_definitions() {
+        let mut options = get_default_options();
+        options.definition_lists = true;
+
+        let md = "Term\n:   First paragraph\n\n    Second *paragraph*\n\n    - item\n\nAfter\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<dl>\n<dt>Term</dt>\n<dd><p>First paragraph</p>\n<p>Second <em>paragraph</em></p>\n<ul>\n<li>item</li>\n</ul>\n</dd>\n</dl>\n<p>After</p>\n");
+    }
+
+    #[test]
+    fn should_not_render_definition_lists_by_default() {
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse("Term\n:   Definition\n", None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>Term\n:   Definition</p>\n");
+    }
+
+    #[test]
+    fn should_use_explicit_heading_attributes() {
+        let mut options = get_default_options();
+        options.attributes = true;
+
+        let md = "# Install {#setup .title data-x=1}\n\n# Setup\n\nSub {.sub}\n---\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<h1 id=\"setup\" class=\"title\" data-x=\"1\">Install</h1>\n<h1 id=\"setup-1\">Setup</h1>\n<h2 id=\"sub\" class=\"sub\">Sub</h2>\n");
+    }
+
+    #[test]
+    fn should_reserve_explicit_ids_declared_after_the_slug() {
+        let mut options = get_default_options();
+        options.attributes = true;
+
+        let actual_html = Marked::new(None).parse("# foo\n\n# Bar {#foo}\n", Some(options), None);
+        pretty_assertions::assert_eq!(actual_html, "<h1 id=\"foo-1\">foo</h1>\n<h1 id=\"foo\">Bar</h1>\n");
+
+        let ids = anchor_ids("# foo\n\n# Bar {#foo}\n", Some(options));
+        assert!(ids.contains("foo") && ids.contains("foo-1"));
+    }
+
+    #[test]
+    fn should_pass_attributes_to_code_links_and_images() {
+        let mut options = get_default_options();
+        options.attributes = true;
+
+        let md = "```js {#example .numbered}\nlet a = 1;\n```\n\n[link](/url){.btn target=_blank} ![img](/a.png){width=20 onload=x}\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<pre id=\"example\" class=\"numbered\"><code class=\"language-js\">let a = 1;\n</code></pre>\n<p><a href=\"/url\" class=\"btn\" target=\"_blank\">link</a> <img src=\"/a.png\" alt=\"img\" width=\"20\"></p>\n");
+    }
+
+    #[test]
+    fn should_keep_invalid_attribute_lists_as_text() {
+        let mut options = get_default_options();
+        options.attributes = true;
+
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse("# Sets {a, b}\n", Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<h1 id=\"sets-a-b\">Sets {a, b}</h1>\n");
+    }
+
+    #[test]
+    fn should_not_let_attributes_override_cleaned_ones() {
+        let mut options = get_default_options();
+        options.attributes = true;
+
+        let md = "# Hi {style=color:red .x}\n\n[a](http://ok.test){href=javascript:alert(1) title=t} ![b](/b.png){src=/evil.png alt=c}\n";
+        let actual_html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(actual_html, "<h1 id=\"hi\" class=\"x\">Hi</h1>\n<p><a href=\"http://ok.test\">a</a> <img src=\"/b.png\" alt=\"b\"></p>\n");
+
+        options.style_attributes = true;
+        let actual_html = Marked::new(None).parse("# Hi {style=color:red}\n", Some(options), None);
+        pretty_assertions::assert_eq!(actual_html, "<h1 id=\"hi\" style=\"color:red\">Hi</h1>\n");
+    }
+
+    #[test]
+    fn should_replace_emoji_shortcodes() {
+        let mut options = get_default_options();
+        options.emoji = true;
+
+        let md = "Shipped :tada: at 10:30:45 :not_an_emoji: `:tada:` https://example.com/:tada:/x\n\n    :tada:\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>Shipped 🎉 at 10:30:45 :not_an_emoji: <code>:tada:</code> <a href=\"https://example.com/:tada:/x\">https://example.com/:tada:/x</a></p>\n<pre><code>:tada:\n</code></pre>\n");
+    }
+
+    #[test]
+    fn should_render_emoji_images_and_custom_aliases() {
+        let mut options = get_default_options();
+        options.emoji = true;
+        options.emoji_image_url = "https://cdn.example.com/{name}/{code}.png";
+        options.emoji_aliases = &[("party", "🥳")];
+
+        let md = ":+1: :party: :octocat:";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><img class=\"emoji\" title=\":+1:\" alt=\":+1:\" src=\"https://cdn.example.com/+1/1f44d.png\"> <img class=\"emoji\" title=\":party:\" alt=\":party:\" src=\"https://cdn.example.com/party/1f973.png\"> <img class=\"emoji\" title=\":octocat:\" alt=\":octocat:\" src=\"https://cdn.example.com/octocat/.png\"></p>\n");
+    }
+
+    #[test]
+    fn should_not_replace_emoji_by_default() {
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse("Hello :wave: and :octocat:", None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>Hello :wave: and :octocat:</p>\n");
+    }
+
+    #[test]
+    fn should_autolink_issue_commit_and_mention_references() {
+        let mut options = get_default_options();
+        options.references = true;
+        options.reference_repo = "org/app";
+
+        let md = "Fixes #12 and other/lib#3, see a5c3785ed8d6a35868bc169f07e40e889087fd2e by @octo-cat.";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>Fixes <a href=\"https://github.com/org/app/issues/12\" class=\"issue-link\">#12</a> and <a href=\"https://github.com/other/lib/issues/3\" class=\"issue-link\">other/lib#3</a>, see <a href=\"https://github.com/org/app/commit/a5c3785ed8d6a35868bc169f07e40e889087fd2e\" class=\"commit-link\">a5c3785</a> by <a href=\"https://github.com/octo-cat\" class=\"user-mention\">@octo-cat</a>.</p>\n");
+    }
+
+    #[test]
+    fn should_not_autolink_references_inside_words_code_or_links() {
+        let mut options = get_default_options();
+        options.references = true;
+        options.reference_repo = "org/app";
+        options.mention_url = "";
+        options.mangle = false;
+
+        let md = "a#1 `#2` [see #3](/x) &#35;4 mail@example.com @nobody deadbeef 1234567";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>a#1 <code>#2</code> <a href=\"/x\">see #3</a> &#35;4 <a href=\"mailto:mail@example.com\">mail@example.com</a> @nobody deadbeef 1234567</p>\n");
+    }
+
+    #[test]
+    fn should_use_custom_reference_url_templates() {
+        let mut options = get_default_options();
+        options.references = true;
+        options.issue_url = "https://tracker.example.com/{number}";
+        options.commit_url = "https://git.example.com/{repo}/-/commit/{sha}";
+
+        let md = "#7 and 0a1b2c3d without a default repository";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><a href=\"https://tracker.example.com/7\" class=\"issue-link\">#7</a> and 0a1b2c3d without a default repository</p>\n");
+    }
+
+    fn resolve_wiki_page(target: &str) -> WikiLink {
+        WikiLink {
+            href: format!("/wiki/{}", target.replace(' ', "_")),
+            missing: target == "Roadmap"
+        }
+    }
+
+    #[test]
+    fn should_resolve_wiki_links() {
+        let mut options = get_default_options();
+        options.wiki_links = true;
+        options.wiki_link_resolver = Some(resolve_wiki_page);
+
+        let md = "See [[Getting Started]], [[Install Guide#Linux Setup|installing]] and [[Roadmap]].";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>See <a href=\"/wiki/Getting_Started\">Getting Started</a>, <a href=\"/wiki/Install_Guide#linux-setup\">installing</a> and <a href=\"/wiki/Roadmap\" class=\"new\">Roadmap</a>.</p>\n");
+    }
+
+    #[test]
+    fn should_slug_wiki_links_without_resolver() {
+        let mut options = get_default_options();
+        options.wiki_links = true;
+
+        let md = "[[Release Notes]] [[#Known Issues]] `[[Code]]`";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><a href=\"release-notes\">Release Notes</a> <a href=\"#known-issues\">#Known Issues</a> <code>[[Code]]</code></p>\n");
+
+        let actual_html = marked.parse("[[Release Notes]]", None, None);
+        pretty_assertions::assert_eq!(actual_html, "<p>[[Release Notes]]</p>\n");
+    }
+
+    #[test]
+    fn should_list_wiki_link_targets() {
+        let md = "# Index\n\n- [[Home]]\n- [[Guide#Setup|setup]]\n\n| Page |\n| --- |\n| [[Home]] and [[FAQ]] |\n";
+        let mut marked = Marked::new(None);
+        let targets = marked.wiki_link_targets(md, None);
+
+        pretty_assertions::assert_eq!(targets, vec!["Home", "Guide", "FAQ"]);
+    }
+
+    #[test]
+    fn should_render_mark_sup_sub_and_ins() {
+        let mut options = get_default_options();
+        options.mark = true;
+        options.superscript = true;
+        options.subscript = true;
+        options.inserted = true;
+
+        let md = "==*hi*== ++new++ 2^10^ H~2~O ~~gone~~ `==code==` ^not sup^";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><mark><em>hi</em></mark> <ins>new</ins> 2<sup>10</sup> H<sub>2</sub>O <del>gone</del> <code>==code==</code> ^not sup^</p>\n");
+    }
+
+    #[test]
+    fn should_leave_unmatched_extended_delimiters_as_text() {
+        let mut options = get_default_options();
+        options.mark = true;
+        options.inserted = true;
+
+        let md = "a == b ++ c ==\u{fc}== d ==e ++f ++g+ h";
+        let actual_html = Marked::new(None).parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>a == b ++ c <mark>\u{fc}</mark> d ==e ++f ++g+ h</p>\n");
+    }
+
+    #[test]
+    fn should_toggle_extended_inline_formatting_individually() {
+        let mut options = get_default_options();
+        options.mark = true;
+
+        let md = "==hi== 2^10^ H~2~O ++new++\n\ni++ and j++, a == b == c";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><mark>hi</mark> 2^10^ H<del>2</del>O ++new++</p>\n<p>i++ and j++, a == b == c</p>\n");
+    }
+
+    #[test]
+    fn should_not_render_extended_inline_formatting_by_default() {
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse("==hi== 2^10^ ++new++", None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>==hi== 2^10^ ++new++</p>\n");
+    }
+
+    #[test]
+    fn should_wrap_abbreviations() {
+        let mut options = get_default_options();
+        options.abbreviations = true;
+
+        let md = "The HTML spec, by the W3C. HTMLX `HTML` [HTML](/x) <code>HTML</code>\n\n*[HTML]: Hyper Text Markup Language\n*[W3C]: World Wide Web Consortium\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>The <abbr title=\"Hyper Text Markup Language\">HTML</abbr> spec, by the <abbr title=\"World Wide Web Consortium\">W3C</abbr>. HTMLX <code>HTML</code> <a href=\"/x\">HTML</a> <code>HTML</code></p>\n");
+    }
+
+    #[test]
+    fn should_collect_abbreviation_definitions() {
+        let mut options = get_default_options();
+        options.abbreviations = true;
+
+        let md = "*[CSS]: Cascading Style Sheets\n*[CSS]: Ignored duplicate\n*[R&D]: Research & Development\n\n- CSS and R&D\n";
+        let mut lexer = Lexer::new(options);
+        lexer.lex(md);
+
+        pretty_assertions::assert_eq!(lexer.get_abbreviations(), vec![
+            Abbreviation { abbr: "CSS".to_string(), title: "Cascading Style Sheets".to_string() },
+            Abbreviation { abbr: "R&D".to_string(), title: "Research & Development".to_string() }
+        ]);
+
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(actual_html, "<ul>\n<li><abbr title=\"Cascading Style Sheets\">CSS</abbr> and <abbr title=\"Research &amp; Development\">R&amp;D</abbr></li>\n</ul>\n");
+    }
+
+    #[test]
+    fn should_not_collect_abbreviations_by_default() {
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse("HTML\n\n*[HTML]: Hyper Text Markup Language\n", None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>HTML</p>\n<p>*[HTML]: Hyper Text Markup Language</p>\n");
+    }
+
+    #[test]
+    fn should_render_table_captions() {
+        let mut options = get_default_options();
+        options.table_extensions = true;
+
+        let md = "[Prices *2024*]\n| Item | Price |\n| --- | ---: |\n| Tea | 3 |\n\n| A |\n| - |\n| 1 |\n\nTable: Second table\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<table>\n<caption>Prices <em>2024</em></caption>\n<thead>\n<tr>\n<th>Item</th>\n<th align=\"right\">Price</th>\n</tr>\n</thead>\n<tbody><tr>\n<td>Tea</td>\n<td align=\"right\">3</td>\n</tr>\n</tbody></table>\n<table>\n<caption>Second table</caption>\n<thead>\n<tr>\n<th>A</th>\n</tr>\n</thead>\n<tbody><tr>\n<td>1</td>\n</tr>\n</tbody></table>\n");
+    }
+
+    #[test]
+    fn should_render_table_colspans_and_multi_line_rows() {
+        let mut options = get_default_options();
+        options.table_extensions = true;
+
+        let md = "| Name || Notes |\n| --- | :-: | --- |\n| a || wide |\n| b | | first \\\n| | | second |\n[Summary]\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<table>\n<caption>Summary</caption>\n<thead>\n<tr>\n<th colspan=\"2\">Name</th>\n<th>Notes</th>\n</tr>\n</thead>\n<tbody><tr>\n<td colspan=\"2\">a</td>\n<td>wide</td>\n</tr>\n<tr>\n<td>b</td>\n<td align=\"center\"></td>\n<td>first<br>second</td>\n</tr>\n</tbody></table>\n");
+    }
+
+    #[test]
+    fn should_not_extend_tables_by_default() {
+        let md = "| A || B |\n| - | - | - |\n| 1 | 2 | 3 |\n[caption]\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<table>\n<thead>\n<tr>\n<th>A</th>\n<th></th>\n<th>B</th>\n</tr>\n</thead>\n<tbody><tr>\n<td>1</td>\n<td>2</td>\n<td>3</td>\n</tr>\n<tr>\n<td>[caption]</td>\n<td></td>\n<td></td>\n</tr>\n</tbody></table>\n");
+    }
+
+    #[test]
+    fn should_render_nested_containers() {
+        let mut options = get_default_options();
+        options.containers = true;
+
+        let md = "::: warning Be careful\nOuter *text*\n\n::: details Show more\n- item\n\n```\n:::\n```\n:::\n:::\n\nafter\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<div class=\"warning\">\n<p class=\"container-title\">Be careful</p>\n<p>Outer <em>text</em></p>\n<details>\n<summary>Show more</summary>\n<ul>\n<li>item</li>\n</ul>\n<pre><code>:::\n</code></pre>\n</details>\n</div>\n<p>after</p>\n");
+    }
+
+    #[test]
+    fn should_start_containers_directly_after_a_paragraph() {
+        let mut options = get_default_options();
+        options.containers = true;
+
+        let md = "para\n::: note\nbody\n:::\n\n> quoted\n> ::: tip\n> hint\n> :::\n";
+        let actual_html = Marked::new(None).parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>para</p>\n<div class=\"note\">\n<p>body</p>\n</div>\n<blockquote>\n<p>quoted</p>\n<div class=\"tip\">\n<p>hint</p>\n</div>\n</blockquote>\n");
+    }
+
+    #[test]
+    fn should_use_registered_container_renderers() {
+        fn render_spoiler(args: &str, body: &str) -> String {
+            format!("<section class=\"spoiler\" data-label=\"{}\">\n{}</section>\n", args, body)
+        }
+
+        let mut options = get_default_options();
+        options.containers = true;
+        options.container_renderers = &[("spoiler", render_spoiler)];
+
+        let md = ":::: spoiler Ending\n::: tip\nHidden\n:::\n::::\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<section class=\"spoiler\" data-label=\"Ending\">\n<div class=\"tip\">\n<p>Hidden</p>\n</div>\n</section>\n");
+    }
+
+    #[test]
+    fn should_not_parse_containers_by_default() {
+        let md = "::: note\ntext\n:::\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>::: note\ntext\n:::</p>\n");
+    }
+
+    #[test]
+    fn should_pass_diagram_fences_to_the_client_library() {
+        let mut options = get_default_options();
+        options.diagrams = true;
+
+        let md = "```mermaid\ngraph TD\n  A-->B & C\n```\n\n```dot\ndigraph { a -> b }\n```\n\n```js\na -> b\n```\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<pre class=\"mermaid\">graph TD\n  A--&gt;B &amp; C\n</pre>\n<div class=\"diagram\" data-lang=\"dot\">digraph { a -&gt; b }\n</div>\n<pre><code class=\"language-js\">a -&gt; b\n</code></pre>\n");
+    }
+
+    #[test]
+    fn should_escape_markup_in_diagram_sources() {
+        let mut options = get_default_options();
+        options.diagrams = true;
+        options.raw_html = HtmlMode::Strip;
+
+        let md = "```mermaid\n</pre><script>alert(1)</script>\n```\n\n```dot\n</div><script>alert(1)</script>\n```\n";
+        let actual_html = Marked::new(None).parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<pre class=\"mermaid\">&lt;/pre&gt;&lt;script&gt;alert(1)&lt;/script&gt;\n</pre>\n<div class=\"diagram\" data-lang=\"dot\">&lt;/div&gt;&lt;script&gt;alert(1)&lt;/script&gt;\n</div>\n");
+    }
+
+    #[test]
+    fn should_merge_diagram_classes_from_attributes() {
+        let mut options = get_default_options();
+        options.diagrams = true;
+        options.attributes = true;
+
+        let md = "```mermaid {#flow .big}\npie\n```\n\n```dot {.wide}\na\n```\n";
+        let actual_html = Marked::new(None).parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<pre class=\"mermaid big\" id=\"flow\">pie\n</pre>\n<div class=\"diagram wide\" data-lang=\"dot\">a\n</div>\n");
+    }
+
+    #[test]
+    fn should_replace_diagrams_with_rendered_output() {
+        fn render_svg(lang: &str, source: &str) -> Option<String> {
+            if lang == "plantuml" {
+                return Some(format!("<svg data-lines=\"{}\"></svg>", source.lines().count()));
+            }
+            None
+        }
+
+        let mut options = get_default_options();
+        options.diagrams = true;
+        options.diagram_languages = &["plantuml", "mermaid"];
+        options.diagram_renderer = Some(render_svg);
+
+        let md = "```plantuml\n@startuml\nA -> B\n@enduml\n```\n\n```mermaid\npie\n```\n\n```dot\na\n```\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<svg data-lines=\"3\"></svg>\n<pre class=\"mermaid\">pie\n</pre>\n<pre><code class=\"language-dot\">a\n</code></pre>\n");
+    }
+
+    #[test]
+    fn should_escape_diagram_fences_by_default() {
+        let md = "```mermaid\nA-->B\n```\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, None, None);
+
+        pretty_assertions::assert_eq!(actual_html, "<pre><code class=\"language-mermaid\">A--&gt;B\n</code></pre>\n");
+    }
+
+    #[test]
+    fn should_use_locale_quotation_marks() {
+        let md = "\"Er sagte 'nein'.\" It's";
+        let mut expected = vec![
+            (SmartypantsLocale::En, "<p>\u{201c}Er sagte \u{2018}nein\u{2019}.\u{201d} It\u{2019}s</p>\n"),
+            (SmartypantsLocale::De, "<p>\u{201e}Er sagte \u{201a}nein\u{2018}.\u{201c} It\u{2019}s</p>\n"),
+            (SmartypantsLocale::Fr, "<p>\u{00ab}\u{202f}Er sagte \u{2039}\u{202f}nein\u{202f}\u{203a}.\u{202f}\u{00bb} It\u{2019}s</p>\n"),
+            (SmartypantsLocale::Ja, "<p>\u{300c}Er sagte \u{300e}nein\u{300f}.\u{300d} It\u{2019}s</p>\n")
+        ];
+
+        for (locale, expected_html) in expected.drain(..) {
+            let mut options = get_default_options();
+            options.smartypants = true;
+            options.smartypants_locale = locale;
+
+            let mut marked = Marked::new(None);
+            let actual_html = marked.parse(md, Some(options), None);
+
+            pretty_assertions::assert_eq!(actual_html, expected_html, "locale {:?}", locale);
+        }
+    }
+
+    #[test]
+    fn should_handle_apostrophes_in_smartypants() {
+        let mut options = get_default_options();
+        options.smartypants = true;
+        options.smartypants_locale = SmartypantsLocale::De;
+
+        let md = "'Tis the '90s: rock'n'roll and rock 'n' roll, 'quoted'";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>\u{2019}Tis the \u{2019}90s: rock\u{2019}n\u{2019}roll and rock \u{2019}n\u{2019} roll, \u{201a}quoted\u{2018}</p>\n");
+    }
+
+    #[test]
+    fn should_apply_typographic_replacements() {
+        let mut options = get_default_options();
+        options.smartypants = true;
+
+        let md = "(c) 2024 Acme(TM) (r) +-5 and 1/2 1/4 3/4 cup, not 11/2 or 1/23";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>\u{00a9} 2024 Acme\u{2122} \u{00ae} \u{00b1}5 and \u{00bd} \u{00bc} \u{00be} cup, not 11/2 or 1/23</p>\n");
+    }
+
+    #[test]
+    fn should_render_interactive_task_items() {
+        let mut options = get_default_options();
+        options.task_list_data = true;
+        options.task_list_interactive = true;
+
+        let md = "# Todo\n\n- [x] done\n- plain\n  - [ ] nested\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<h1 id=\"todo\">Todo</h1>\n<ul>\n<li><input checked=\"\" type=\"checkbox\" data-task-index=\"0\" data-line=\"3\"> done</li>\n<li>plain<ul>\n<li><input type=\"checkbox\" data-task-index=\"1\" data-line=\"5\"> nested</li>\n</ul>\n</li>\n</ul>\n");
+    }
+
+    #[test]
+    fn should_toggle_tasks_byte_exactly() {
+        let md = "```\n- [ ] not a task\n```\r\n\r\n- [ ] not a task\r\n-\t[X] second\r\n\r\n> 1. [ ] quoted\r\n";
+
+        let found = tasks(md, None);
+        pretty_assertions::assert_eq!(found.iter().map(|t| (t.index, t.line, t.offset, t.checked)).collect::<Vec<_>>(),
+                                      vec![(0, 5, 30, false), (1, 6, 48, true), (2, 8, 67, false)]);
+        pretty_assertions::assert_eq!(found[1].text, "second");
+
+        pretty_assertions::assert_eq!(toggle_task(md, 0), md.replacen("- [ ] not a task\r\n", "- [x] not a task\r\n", 1));
+        pretty_assertions::assert_eq!(toggle_task(md, 1), md.replace("[X]", "[ ]"));
+        pretty_assertions::assert_eq!(toggle_task(md, 2), md.replace("1. [ ]", "1. [x]"));
+        pretty_assertions::assert_eq!(toggle_task(md, 3), md);
+    }
+
+    #[test]
+    fn should_summarize_task_completion() {
+        let md = "- [x] a\n- [ ] b\n  - [x] c\n\n* [ ] d\n";
+
+        pretty_assertions::assert_eq!(task_summary(md), TaskSummary { done: 2, total: 4 });
+        pretty_assertions::assert_eq!(task_summary(toggle_task(md, 3).as_str()), TaskSummary { done: 3, total: 4 });
+        pretty_assertions::assert_eq!(task_summary("no tasks"), TaskSummary { done: 0, total: 0 });
+
+        let mut options = get_default_options();
+        options.gfm = false;
+        pretty_assertions::assert_eq!(task_summary_with_options(md, options), TaskSummary { done: 0, total: 0 });
+        pretty_assertions::assert_eq!(toggle_task_with_options(md, 0, options), md);
+    }
+
+    #[test]
+    fn should_inspect_link_reference_definitions() {
+        let md = "See [the docs][Docs] and [API][] or [missing][nope].\n\n[docs]: https://example.com/docs \"Docs\"\n[api]: /api\n[DOCS]: /duplicate\n[unused]: /unused\n\n> ![logo][Logo] and [shortcut]\n>\n> [logo]: /logo.png\n";
+        let report = inspect_references(md, None);
+
+        let definitions: Vec<(&str, &str, usize, usize, usize, usize)> = report.definitions.iter()
+            .map(|d| (d.label.as_str(), d.href.as_str(), d.span.start, d.span.end, d.span.line, d.span.column))
+            .collect();
+        pretty_assertions::assert_eq!(definitions, vec![
+            ("docs", "https://example.com/docs", 54, 93, 3, 1),
+            ("api", "/api", 94, 105, 4, 1),
+            ("unused", "/unused", 125, 142, 6, 1),
+            ("logo", "/logo.png", 179, 196, 10, 3)
+        ]);
+
+        let uses: Vec<(&str, ReferenceKind, bool, usize, usize, usize)> = report.definitions.iter()
+            .flat_map(|d| d.references.iter())
+            .map(|r| (r.label.as_str(), r.kind, r.image, r.span.start, r.span.end, r.span.column))
+            .collect();
+        pretty_assertions::assert_eq!(uses, vec![
+            ("Docs", ReferenceKind::Full, false, 4, 20, 5),
+            ("API", ReferenceKind::Collapsed, false, 25, 32, 26),
+            ("Logo", ReferenceKind::Full, true, 146, 159, 3)
+        ]);
+
+        pretty_assertions::assert_eq!(report.unused.iter().map(|d| d.label.as_str()).collect::<Vec<_>>(), vec!["unused"]);
+        pretty_assertions::assert_eq!(report.duplicates.iter().map(|d| (d.label.as_str(), d.span.line)).collect::<Vec<_>>(), vec![("DOCS", 5)]);
+
+        let undefined: Vec<(&str, ReferenceKind, usize, usize)> = report.undefined.iter()
+            .map(|r| (r.label.as_str(), r.kind, r.span.start, r.span.line))
+            .collect();
+        pretty_assertions::assert_eq!(undefined, vec![
+            ("nope", ReferenceKind::Full, 36, 1),
+            ("shortcut", ReferenceKind::Shortcut, 164, 8)
+        ]);
+    }
+
+    #[test]
+    fn should_match_reference_labels_with_case_folding() {
+        pretty_assertions::assert_eq!(normalize_label("  Foo \n\t bar "), normalize_label("FOO BAR"));
+        pretty_assertions::assert_eq!(normalize_label("\u{1e9e}"), normalize_label("ss"));
+
+        let md = "[\u{1e9e}] and [Foo\n  bar]\n\n[SS]: /ss\n[foo bar]: /fb\n";
+        let report = inspect_references(md, None);
+
+        pretty_assertions::assert_eq!(report.definitions.iter().map(|d| d.references.len()).collect::<Vec<_>>(), vec![1, 1]);
+        pretty_assertions::assert_eq!(report.definitions[1].references[0].span.end, 21);
+        assert!(report.unused.is_empty());
+        assert!(report.undefined.is_empty());
+    }
+
+    #[test]
+    fn should_end_spans_before_crlf_line_breaks() {
+        let md = "a\r\n[api.c]\r\n\r\n[x]: /x\r\n";
+        let report = inspect_references(md, None);
+
+        let undefined: Vec<(usize, usize, usize, usize)> = report.undefined.iter()
+            .map(|r| (r.span.start, r.span.end, r.span.line, r.span.column))
+            .collect();
+        pretty_assertions::assert_eq!(undefined, vec![(3, 10, 2, 1)]);
+
+        let definitions: Vec<(usize, usize, usize)> = report.definitions.iter()
+            .map(|d| (d.span.start, d.span.end, d.span.line))
+            .collect();
+        pretty_assertions::assert_eq!(definitions, vec![(14, 21, 4)]);
+    }
+
+    #[test]
+    fn should_locate_tasks_after_link_definitions() {
+        let md = "[x]: /x\n\n- [ ] a\n- [x] b\n";
+        let found = tasks(md, None);
+
+        pretty_assertions::assert_eq!(found.iter().map(|t| (t.line, t.offset)).collect::<Vec<_>>(), vec![(3, 11), (4, 19)]);
+        pretty_assertions::assert_eq!(toggle_task(md, 0), "[x]: /x\n\n- [x] a\n- [x] b\n");
+    }
+
+    #[test]
+    fn should_resolve_broken_references_with_callback() {
+        fn resolve_symbol(label: &str, span: &Span) -> Option<(String, String)> {
+            if label.starts_with("Vec::") {
+                let name = label.trim_start_matches("Vec::");
+                return Some((format!("https://doc.rust-lang.org/std/vec/struct.Vec.html#method.{}", name), format!("{} at {}:{}", label, span.line, span.column)));
+            }
+            None
+        }
+
+        let mut options = get_default_options();
+        options.broken_link_callback = Some(resolve_symbol);
+
+        let md = "Use [Vec::push] or [append][Vec::append].\n\n![icon][Vec::icon] and [unknown]\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p>Use <a href=\"https://doc.rust-lang.org/std/vec/struct.Vec.html#method.push\" title=\"Vec::push at 1:5\">Vec::push</a> or <a href=\"https://doc.rust-lang.org/std/vec/struct.Vec.html#method.append\" title=\"Vec::append at 1:20\">append</a>.</p>\n<p><img src=\"https://doc.rust-lang.org/std/vec/struct.Vec.html#method.icon\" alt=\"icon\" title=\"Vec::icon at 3:1\"> and [unknown]</p>\n");
+    }
+
+    #[test]
+    fn should_only_report_undefined_references_to_callback() {
+        static CALLS: Mutex<Vec<(String, usize, usize)>> = Mutex::new(Vec::new());
+
+        fn record(label: &str, span: &Span) -> Option<(String, String)> {
+            CALLS.lock().unwrap().push((label.to_string(), span.start, span.end));
+            None
+        }
+
+        let mut options = get_default_options();
+        options.broken_link_callback = Some(record);
+
+        let md = "[a] [b][Missing Ref] [c][]\n\n[a]: /a\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><a href=\"/a\">a</a> [b][Missing Ref] [c][]</p>\n");
+        pretty_assertions::assert_eq!(*CALLS.lock().unwrap(), vec![
+            ("Missing Ref".to_string(), 4, 20),
+            ("c".to_string(), 21, 26)
+        ]);
+    }
+
+    #[test]
+    fn should_prefer_document_definitions_over_callback() {
+        fn resolve_everything(label: &str, _span: &Span) -> Option<(String, String)> {
+            Some((format!("/external/{}", label), "".to_string()))
+        }
+
+        let mut options = get_default_options();
+        options.broken_link_callback = Some(resolve_everything);
+
+        let md = "[Local] and [remote]\n\n[local]: /local\n";
+        let mut marked = Marked::new(None);
+        let actual_html = marked.parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><a href=\"/local\">Local</a> and <a href=\"/external/remote\">remote</a></p>\n");
+    }
+
+    #[test]
+    fn should_ask_callback_once_per_resolved_label() {
+        static CALLS: Mutex<Vec<(String, usize)>> = Mutex::new(Vec::new());
+
+        fn resolve(label: &str, span: &Span) -> Option<(String, String)> {
+            CALLS.lock().unwrap().push((label.to_string(), span.start));
+            if label == "known" { Some(("/known".to_string(), "".to_string())) } else { None }
+        }
+
+        let mut options = get_default_options();
+        options.broken_link_callback = Some(resolve);
+
+        let md = "[known] [other]\r\n\r\n> [Known] [other]\r\n";
+        let actual_html = Marked::new(None).parse(md, Some(options), None);
+
+        pretty_assertions::assert_eq!(actual_html, "<p><a href=\"/known\">known</a> [other]</p>\n<blockquote>\n<p><a href=\"/known\">Known</a> [other]</p>\n</blockquote>\n");
+        pretty_assertions::assert_eq!(*CALLS.lock().unwrap(), vec![
+            ("known".to_string(), 0),
+            ("other".to_string(), 8),
+            ("other".to_string(), 29)
+        ]);
+    }
+
+    #[test]
+    fn should_lint_markdown_with_default_rules() {
+        let md = "# Title\n\n### Skipped\n\n## Title\n\nVisit https://example.com now.  \nnext line \n\n* one\n* two\n\n- three\n\n```\n# Title\n```\n\n![](/img.png) and [missing][ref] and [shortcut]\n";
+        let diagnostics = lint(md, &get_default_lint_options());
+
+        let found: Vec<(&str, Severity, usize, usize)> = diagnostics.iter()
+            .map(|d| (d.rule, d.severity, d.span.line, d.span.column))
+            .collect();
+        pretty_assertions::assert_eq!(found, vec![
+            ("heading-increment", Severity::Warning, 3, 1),
+            ("no-duplicate-heading-slugs", Severity::Warning, 5, 1),
+            ("no-bare-urls", Severity::Warning, 7, 7),
+            ("no-trailing-spaces", Severity::Warning, 8, 10),
+            ("list-marker-style", Severity::Warning, 13, 1),
+            ("fenced-code-language", Severity::Warning, 15, 1),
+            ("image-alt-text", Severity::Warning, 19, 1),
+            ("no-undefined-references", Severity::Error, 19, 19)
+        ]);
+        pretty_assertions::assert_eq!(diagnostics[1].message, "Heading slug \"title\" is already used on line 1");
+        pretty_assertions::assert_eq!(&md[diagnostics[2].span.start..diagnostics[2].span.end], "https://example.com");
+    }
+
+    #[test]
+    fn should_configure_lint_rules() {
+        let md = "- a\n\n* b\n\n[x]\n\n#### Deep\n";
+
+        let mut config = get_default_lint_options();
+        config.list_marker = "*";
+        config.undefined_shortcuts = true;
+        config.heading_increment.enabled = false;
+        config.no_undefined_references = Rule { enabled: true, severity: Severity::Warning };
+
+        let found: Vec<(&str, Severity, usize)> = lint(md, &config).iter()
+            .map(|d| (d.rule, d.severity, d.span.line))
+            .collect();
+        pretty_assertions::assert_eq!(found, vec![
+            ("list-marker-style", Severity::Warning, 1),
+            ("no-undefined-references", Severity::Warning, 5)
+        ]);
+    }
+
+    #[test]
+    fn should_not_report_clean_documents() {
+        let md = "# Guide\n\n## Setup {#setup-guide}\n\n## Setup\n\nLine with break  \ncontinues <https://example.com>.\n\n- [Link][docs]\n\n```sh\ncargo build\n```\n\n    indented code\n\n![Logo](/logo.png)\n\n[docs]: /docs\n";
+        let mut config = get_default_lint_options();
+        config.options.attributes = true;
+
+        pretty_assertions::assert_eq!(lint(md, &config), vec![]);
+    }
+
+    #[test]
+    fn should_check_anchor_links_between_documents() {
+        let readme = "# Install Guide\n\n## Setup\n\n## Setup\n\n<div id=\"raw-anchor\"></div>\n\n[a](#setup) [b](#setup-1) [c](#raw-anchor) [d](#nope)\n\n[guide](docs/guide.md#usage) [gone](docs/missing.md) [bad](docs/guide.md#nowhere)\n\n![logo](logo.png) [ext](https://example.com#x)\n";
+        let guide = "## Usage\n\nBack to [install](../README.md#install-guide) or [usage](../README.md#usage).\n";
+
+        let broken = check_documents(&[("README.md", readme), ("docs/guide.md", guide)], None);
+        let found: Vec<(&str, &str, Problem, usize, usize)> = broken.iter()
+            .map(|b| (b.path.as_str(), b.href.as_str(), b.problem, b.span.line, b.span.column))
+            .collect();
+
+        pretty_assertions::assert_eq!(found, vec![
+            ("README.md", "#nope", Problem::MissingAnchor, 9, 44),
+            ("README.md", "docs/missing.md", Problem::MissingFile, 11, 30),
+            ("README.md", "docs/guide.md#nowhere", Problem::MissingAnchor, 11, 54),
+            ("docs/guide.md", "../README.md#usage", Problem::MissingAnchor, 3, 50)
+        ]);
+    }
+
+    #[test]
+    fn should_check_relative_files_on_disk() {
+        let dir = std::env::temp_dir().join(format!("marked-rs-anchors-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("logo.png"), "").unwrap();
+        fs::write(dir.join("guide.md"), "# Guide\n").unwrap();
+
+        let doc = dir.join("index.md");
+        fs::write(&doc, "# Home\n\n![logo](logo.png) ![icon](icon.png) [top](#home)\n\n[g](guide.md) [g](guide.md#guide) [g](guide.md#nope) [x](gone.md)\n").unwrap();
+
+        let broken = check_files(&[&doc], None).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let found: Vec<(&str, Problem)> = broken.iter().map(|b| (b.href.as_str(), b.problem)).collect();
+        pretty_assertions::assert_eq!(found, vec![
+            ("icon.png", Problem::MissingFile),
+            ("guide.md#nope", Problem::MissingAnchor),
+            ("gone.md", Problem::MissingFile)
+        ]);
+    }
+
+    #[test]
+    fn should_collect_anchor_ids() {
+        let mut options = get_default_options();
+        options.attributes = true;
+        options.header_prefix = "doc-";
+
+        let mut ids: Vec<String> = anchor_ids("# Intro\n\n## Setup {#custom}\n\n## Intro\n\nSee <a name=\"here\"></a>.\n", Some(options))
+            .into_iter()
+            .collect();
+        ids.sort();
+
+        pretty_assertions::assert_eq!(ids, vec!["custom", "doc-intro", "doc-intro-1", "here"]);
+    }
+
+    #[test]
+    fn should_sanitize_raw_html_with_allowlist() {
+        let md = "<div onclick=\"x()\" class=\"note\">\nhi <script>alert('<b>')</script> there\n</div>\n\ntext <a href=\"javascript:alert(1)\" title=\"t\">x</a> <img src=\"java&#9;script:1\" alt=a onerror=\"y\"> <em>ok</em><!-- c -->\n";
+        let mut options = get_default_options();
+        options.sanitize_html = true;
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "<div class=\"note\">\nhi  there\n</div>\n\n<p>text <a title=\"t\">x</a> <img alt=\"a\"> <em>ok</em></p>\n");
+    }
+
+    #[test]
+    fn should_neutralize_security_fixtures() {
+        let dir = "tests/fixtures/marked-specs/security";
+        let names = ["sanitizer_bypass", "sanitizer_bypass_remove_generic", "sanitizer_bypass_remove_script", "sanitizer_bypass_remove_tag"];
+        let lines = |html: &str| html.lines().filter(|l| !l.is_empty()).map(String::from).collect::<Vec<String>>();
+
+        for name in names.iter() {
+            let md = fs::read_to_string(format!("{}/{}.md", dir, name)).unwrap();
+            let expected = fs::read_to_string(format!("{}/{}.html", dir, name)).unwrap();
+            let front_matter = md.splitn(3, "---\n").nth(1).unwrap();
+            let body = md.splitn(3, "---\n").nth(2).unwrap();
+
+            // The deprecated options as the fixtures configure them
+            let mut legacy = get_default_options();
+            legacy.sanitize = true;
+            if front_matter.contains("sanitizer:") {
+                legacy.sanitizer = Some(|_| String::new());
+            }
+            pretty_assertions::assert_eq!(lines(Marked::new(None).parse(body, Some(legacy), None).as_str()), lines(expected.as_str()));
+
+            let mut options = get_default_options();
+            options.sanitize_html = true;
+            let html = Marked::new(None).parse(body, Some(options), None);
+            for unsafe_markup in ["<script", "<sometag", "<img  ", "onerror=\""] {
+                assert!(!html.contains(unsafe_markup), "{} in {}", unsafe_markup, name);
+            }
+        }
+
+        let md = fs::read_to_string(format!("{}/sanitizer_bypass.md", dir)).unwrap();
+        let mut options = get_default_options();
+        options.sanitize_html = true;
+        pretty_assertions::assert_eq!(
+            Marked::new(None).parse(md.splitn(3, "---\n").nth(2).unwrap(), Some(options), None),
+            "<p>AAA &lt;img  src=x onerror=alert(1) /&gt;BBB</p>\n<p>AAA &lt;img  src=x onerror=alert(1)BBB</p>\n<p><a>a2a2</a> b c d</p>\n<h1 id=\"text\"><img src=\"URL\" alt=\"text\"></h1>\n"
+        );
+    }
+
+    #[test]
+    fn should_sanitize_output_with_custom_allowlist() {
+        let mut options = get_default_options();
+        options.sanitize_output = true;
+        options.sanitize_tags = &["p", "em", "a"];
+        options.sanitize_attributes = &[("a", &["href"])];
+        options.sanitize_url_schemes = &[("href", &["https"])];
+
+        let html = Marked::new(None).parse("# Hi *there*\n\n[a](http://x.test) [b](https://x.test \"t\") <span>s</span>\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "Hi <em>there</em>\n<p><a>a</a> <a href=\"https://x.test\">b</a> s</p>\n");
+    }
+
+    #[test]
+    fn should_filter_disallowed_tags_with_gfm_preset() {
+        let data = fs::read_to_string("tests/fixtures/marked-specs/gfm/gfm.0.29.json").unwrap();
+        let specs: Vec<marked_rs::helpers::Spec> = serde_json::from_str(&data).unwrap();
+        let spec = specs.iter().find(|spec| spec.example == 653).unwrap();
+
+        let options = get_base_options(true, false, false, false);
+        pretty_assertions::assert_eq!(Marked::new(None).parse(spec.markdown.as_str(), Some(options), None), spec.html);
+    }
+
+    #[test]
+    fn should_filter_inline_and_closing_tags() {
+        let mut options = get_default_options();
+        options.tagfilter = true;
+
+        let html = Marked::new(None).parse("x <iframe src=\"a\"></iframe> <titles>ok</titles> <SCRIPT/>\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p>x &lt;iframe src=\"a\">&lt;/iframe> <titles>ok</titles> &lt;SCRIPT/></p>\n");
+    }
+
+    #[test]
+    fn should_filter_tags_with_default_options() {
+        let html = Marked::new(None).parse("<script>x</script>\n", None, None);
+        pretty_assertions::assert_eq!(html, "&lt;script>x&lt;/script>\n");
+
+        let mut options = get_default_options();
+        options.tagfilter = false;
+
+        let html = Marked::new(None).parse("<textarea>\n*foo*\n</textarea>\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<textarea>\n*foo*\n</textarea>\n");
+    }
+
+    #[test]
+    fn should_escape_raw_html() {
+        let md = "<div class=\"x\">\n*hi*\n</div>\n\nSay <b>bold</b> &amp; <!-- note -->\n";
+        let mut options = get_default_options();
+        options.raw_html = HtmlMode::Escape;
+        options.html_comments = HtmlMode::Strip;
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "&lt;div class=&quot;x&quot;&gt;\n*hi*\n&lt;/div&gt;\n\n<p>Say &lt;b&gt;bold&lt;/b&gt; &amp; </p>\n");
+    }
+
+    #[test]
+    fn should_strip_raw_html_and_keep_comments() {
+        let md = "<div class=\"x\">\n*hi*\n</div>\n\nSay <b>bold</b> &amp; <!-- note -->\n";
+        let mut options = get_default_options();
+        options.raw_html = HtmlMode::Strip;
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p>Say bold &amp; <!-- note --></p>\n");
+    }
+
+    #[test]
+    fn should_not_pass_raw_text_after_inline_script() {
+        let md = "a <script>alert(\"<img src=x>\")</script>\n";
+        let mut options = get_default_options();
+
+        options.raw_html = HtmlMode::Escape;
+        pretty_assertions::assert_eq!(Marked::new(None).parse(md, Some(options), None),
+                                      "<p>a &lt;script&gt;alert(&quot;&lt;img src=x&gt;&quot;)&lt;/script&gt;</p>\n");
+
+        options.raw_html = HtmlMode::Strip;
+        pretty_assertions::assert_eq!(Marked::new(None).parse(md, Some(options), None),
+                                      "<p>a </p>\n");
+    }
+
+    #[test]
+    fn should_strip_inline_script_and_style_contents() {
+        let md = "a <script>evil()</script> b <STYLE type=\"text/css\">p { color: red }</style> c <b>d</b> <title>t</title>e <script>open()\n";
+        let mut options = get_default_options();
+        options.raw_html = HtmlMode::Strip;
+
+        pretty_assertions::assert_eq!(Marked::new(None).parse(md, Some(options), None),
+                                      "<p>a  b  c d e </p>\n");
+    }
+
+    fn rewrite_urls(url: &str, kind: UrlKind, token: &Token) -> Option<String> {
+        match kind {
+            UrlKind::Image | UrlKind::HtmlSrc              => Some(format!("https://cdn.test/{}", url.trim_start_matches('/'))),
+            UrlKind::Link if url.ends_with(".md")          => Some(url.replace(".md", ".html")),
+            UrlKind::Autolink if url.contains("evil")      => None,
+            UrlKind::HtmlHref if url.starts_with("javascript:") => None,
+            _                                              => Some(url.to_string())
+        }
+    }
+
+    #[test]
+    fn should_rewrite_link_and_image_urls() {
+        let md = "[Guide](docs/guide.md) [ref][r] ![Logo](/logo.png \"L\") <https://evil.test> https://ok.test\n\n[r]: other.md\n";
+        let mut options = get_default_options();
+        options.url_rewriter = Some(rewrite_urls);
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"docs/guide.html\">Guide</a> <a href=\"other.html\">ref</a> <img src=\"https://cdn.test/logo.png\" alt=\"Logo\" title=\"L\"> https://evil.test <a href=\"https://ok.test\">https://ok.test</a></p>\n");
+    }
+
+    #[test]
+    fn should_rewrite_email_autolinks_before_mangling() {
+        fn rewrite_emails(url: &str, kind: UrlKind, _token: &Token) -> Option<String> {
+            match url {
+                "mailto:spam@bar.com" => None,
+                _ if kind == UrlKind::Autolink => Some(url.replace("@bar.com", "@example.com")),
+                _ => Some(url.to_string())
+            }
+        }
+
+        let md = "<foo@bar.com> <spam@bar.com>\n";
+        let mut options = get_default_options();
+        options.url_rewriter = Some(rewrite_emails);
+        options.mangle_strategy = MangleStrategy::Off;
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"mailto:foo@example.com\">foo@bar.com</a> spam@bar.com</p>\n");
+
+        options.mangle_strategy = MangleStrategy::Deterministic;
+        let html = Marked::new(None).parse(md, Some(options), None);
+        assert!(!html.contains("bar.com") && !html.contains("example.com"));
+        assert!(html.contains("href=\"mailto:&#"));
+        assert!(!html.contains("spam"));
+    }
+
+    #[test]
+    fn should_rewrite_raw_html_urls() {
+        let md = "<div><img src=\"a.png\"></div>\n\nSee <a href='javascript:alert(1)' title=\"x\">a</a> <img src=\"pic.png?a=1&amp;b=2\" alt=\"p\">\n";
+        let mut options = get_default_options();
+        options.url_rewriter = Some(rewrite_urls);
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "<div><img src=\"https://cdn.test/a.png\"></div>\n\n<p>See <a title=\"x\">a</a> <img src=\"https://cdn.test/pic.png?a=1&amp;b=2\" alt=\"p\"></p>\n");
+    }
+
+    #[test]
+    fn should_pass_token_context_to_url_rewriter() {
+        fn drop_trackers(url: &str, kind: UrlKind, token: &Token) -> Option<String> {
+            if kind == UrlKind::Image && token.text == "tracker" { None } else { Some(url.to_string()) }
+        }
+
+        let mut options = get_default_options();
+        options.url_rewriter = Some(drop_trackers);
+
+        let html = Marked::new(None).parse("[![tracker](t.gif)](https://x.test) ![chart](c.png)\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"https://x.test\"></a> <img src=\"c.png\" alt=\"chart\"></p>\n");
+    }
+
+    #[test]
+    fn should_mark_external_links() {
+        let md = "[home](https://example.com/a) [docs](https://Docs.Example.com) [rel](/about) [ext](https://other.test/x \"T\") <https://other.test> [proto](//cdn.other.test/y) [mail](mailto:a@b.test)\n";
+        let mut options = get_default_options();
+        options.external_links = true;
+        options.own_hosts = &["example.com", "*.example.com"];
+
+        let external = "rel=\"nofollow noopener noreferrer\" target=\"_blank\"";
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, format!(
+            "<p><a href=\"https://example.com/a\">home</a> <a href=\"https://Docs.Example.com\">docs</a> <a href=\"/about\">rel</a> <a href=\"https://other.test/x\" title=\"T\" {0}>ext</a> <a href=\"https://other.test\" {0}>https://other.test</a> <a href=\"//cdn.other.test/y\" {0}>proto</a> <a href=\"mailto:a@b.test\">mail</a></p>\n",
+            external
+        ));
+    }
+
+    #[test]
+    fn should_configure_external_link_attributes() {
+        let mut options = get_default_options();
+        options.external_links = true;
+        options.external_link_rel = "noopener";
+        options.external_link_target = "";
+        options.external_link_class = "external";
+        options.mailto_link_class = "email";
+
+        let html = Marked::new(None).parse("[a](http://x.test) [m](mailto:m@x.test)\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"http://x.test\" rel=\"noopener\" class=\"external\">a</a> <a href=\"mailto:m@x.test\" class=\"email\">m</a></p>\n");
+    }
+
+    #[test]
+    fn should_prefer_source_attributes_over_link_policy() {
+        let mut options = get_default_options();
+        options.external_links = true;
+        options.attributes = true;
+
+        let html = Marked::new(None).parse("[a](http://x.test){target=_self}\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"http://x.test\" rel=\"nofollow noopener noreferrer\" target=\"_self\">a</a></p>\n");
+
+        let html = Marked::new(None).parse("[a](http://x.test)\n", None, None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"http://x.test\">a</a></p>\n");
+    }
+
+    #[test]
+    fn should_add_image_size_hints_and_loading_attributes() {
+        let md = "![a](x.png =300x200) ![b](y.png =300x \"T\") ![c](z.png =x50) ![d](w.png)\n";
+        let mut options = get_default_options();
+        options.image_size_hints = true;
+        options.image_loading = "lazy";
+        options.image_decoding = "async";
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><img src=\"x.png\" alt=\"a\" width=\"300\" height=\"200\" loading=\"lazy\" decoding=\"async\"> <img src=\"y.png\" alt=\"b\" title=\"T\" width=\"300\" loading=\"lazy\" decoding=\"async\"> <img src=\"z.png\" alt=\"c\" height=\"50\" loading=\"lazy\" decoding=\"async\"> <img src=\"w.png\" alt=\"d\" loading=\"lazy\" decoding=\"async\"></p>\n");
+
+        let html = Marked::new(None).parse("![a](x.png =300x200)\n", None, None);
+        pretty_assertions::assert_eq!(html, "<p>![a](x.png =300x200)</p>\n");
+    }
+
+    #[test]
+    fn should_only_read_size_hints_after_the_destination() {
+        let mut options = get_default_options();
+        options.image_size_hints = true;
+
+        let html = Marked::new(None).parse("![a](x.png \"w =3x4)\")\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><img src=\"x.png\" alt=\"a\" title=\"w =3x4)\"></p>\n");
+
+        let html = Marked::new(None).parse("![a \\](b) é](x.png =3x4 \"t\")\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><img src=\"x.png\" alt=\"a ](b) é\" title=\"t\" width=\"3\" height=\"4\"></p>\n");
+    }
+
+    #[test]
+    fn should_wrap_standalone_images_in_figures() {
+        let md = "![Chart](c.png \"Sales by *month*\")\n\nText ![i](i.png)\n\n![Plain](p.png)\n";
+        let mut options = get_default_options();
+        options.image_figures = true;
+
+        let html = Marked::new(None).parse(md, Some(options), None);
+        pretty_assertions::assert_eq!(html, "<figure>\n<img src=\"c.png\" alt=\"Chart\">\n<figcaption>Sales by *month*</figcaption>\n</figure>\n<p>Text <img src=\"i.png\" alt=\"i\"></p>\n<figure>\n<img src=\"p.png\" alt=\"Plain\">\n</figure>\n");
+    }
+
+    #[test]
+    fn should_take_image_dimensions_from_hook() {
+        fn dimensions(src: &str) -> Option<(u32, u32)> {
+            if src == "known.png" { Some((640, 480)) } else { None }
+        }
+
+        let mut options = get_default_options();
+        options.image_size_hints = true;
+        options.image_dimensions = Some(dimensions);
+
+        let html = Marked::new(None).parse("![k](known.png) ![u](unknown.png) ![h](known.png =10x10)\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><img src=\"known.png\" alt=\"k\" width=\"640\" height=\"480\"> <img src=\"unknown.png\" alt=\"u\"> <img src=\"known.png\" alt=\"h\" width=\"10\" height=\"10\"></p>\n");
+    }
+
+    #[test]
+    fn should_mangle_emails_deterministically() {
+        let md = "<a@b.test> and me@x.test\n";
+        let html = Marked::new(None).parse(md, None, None);
+
+        pretty_assertions::assert_eq!(html, "<p><a href=\"mailto:&#97;&#x40;&#x62;&#46;&#x74;&#101;&#x73;&#x74;\">&#97;&#x40;&#x62;&#46;&#x74;&#101;&#x73;&#x74;</a> and <a href=\"mailto:&#x6d;&#x65;&#64;&#120;&#46;&#116;&#101;&#115;&#x74;\">&#x6d;&#x65;&#64;&#120;&#46;&#116;&#101;&#115;&#x74;</a></p>\n");
+        pretty_assertions::assert_eq!(Marked::new(None).parse(md, None, None), html);
+    }
+
+    #[test]
+    fn should_mangle_emails_with_custom_rng() {
+        fn always_hex() -> f64 { 1.0 }
+        fn always_decimal() -> f64 { 0.0 }
+
+        let mut options = get_default_options();
+        options.mangle_strategy = MangleStrategy::Random;
+
+        options.mangle_rng = Some(always_hex);
+        pretty_assertions::assert_eq!(Marked::new(None).parse("<a@b.c>\n", Some(options), None),
+                                      "<p><a href=\"mailto:&#x61;&#x40;&#x62;&#x2e;&#x63;\">&#x61;&#x40;&#x62;&#x2e;&#x63;</a></p>\n");
+
+        options.mangle_rng = Some(always_decimal);
+        pretty_assertions::assert_eq!(Marked::new(None).parse("<a@b.c>\n", Some(options), None),
+                                      "<p><a href=\"mailto:&#97;&#64;&#98;&#46;&#99;\">&#97;&#64;&#98;&#46;&#99;</a></p>\n");
+
+        options.mangle_rng = None;
+        pretty_assertions::assert_eq!(Marked::new(None).parse("<a@b.test>\n", Some(options), None),
+                                      "<p><a href=\"mailto:&#97;&#x40;&#x62;&#46;&#x74;&#101;&#x73;&#x74;\">&#97;&#x40;&#x62;&#46;&#x74;&#101;&#x73;&#x74;</a></p>\n");
+
+        options.mangle_strategy = MangleStrategy::Off;
+        pretty_assertions::assert_eq!(Marked::new(None).parse("<a@b.c>\n", Some(options), None),
+                                      "<p><a href=\"mailto:a@b.c\">a@b.c</a></p>\n");
+    }
+
+    #[test]
+    fn should_obfuscate_emails_with_css() {
+        let mut options = get_default_options();
+        options.mangle_strategy = MangleStrategy::Css;
+
+        let html = Marked::new(None).parse("<me@x.test>\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"mailto:&#x6d;&#x65;&#64;&#120;&#46;&#116;&#101;&#115;&#x74;\"><span style=\"unicode-bidi:bidi-override;direction:rtl\">tset.x@em</span></a></p>\n");
+
+        options.mangle = false;
+        let html = Marked::new(None).parse("<me@x.test>\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<p><a href=\"mailto:me@x.test\">me@x.test</a></p>\n");
+    }
+
+    #[test]
+    fn should_render_heading_anchors_before_text() {
+        let mut options = get_default_options();
+        options.heading_anchors = HeadingAnchors::Before;
+
+        let html = Marked::new(None).parse("# Title\n\n## Set *up*\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<h1 id=\"title\"><a class=\"anchor\" href=\"#title\" aria-hidden=\"true\">#</a> Title</h1>\n<h2 id=\"set-up\"><a class=\"anchor\" href=\"#set-up\" aria-hidden=\"true\">#</a> Set <em>up</em></h2>\n");
+    }
+
+    #[test]
+    fn should_configure_heading_anchors_per_level() {
+        let mut options = get_default_options();
+        options.heading_anchors = HeadingAnchors::After;
+        options.heading_anchor_levels = &[2, 3];
+        options.heading_anchor_symbol = "<svg class=\"octicon\"></svg>";
+        options.heading_anchor_class = "permalink";
+
+        let html = Marked::new(None).parse("# Title\n\n## Setup\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<h1 id=\"title\">Title</h1>\n<h2 id=\"setup\">Setup <a class=\"permalink\" href=\"#setup\" aria-hidden=\"true\"><svg class=\"octicon\"></svg></a></h2>\n");
+    }
+
+    #[test]
+    fn should_wrap_heading_text_in_anchor() {
+        let mut options = get_default_options();
+        options.heading_anchors = HeadingAnchors::Wrap;
+        options.attributes = true;
+
+        let html = Marked::new(None).parse("## Setup {#install}\n\n## Setup\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<h2 id=\"install\"><a class=\"anchor\" href=\"#install\">Setup</a></h2>\n<h2 id=\"setup\"><a class=\"anchor\" href=\"#setup\">Setup</a></h2>\n");
+
+        let html = Marked::new(None).parse("## See [x](http://y) {id='a\"b'}\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<h2 id=\"a&quot;b\">See <a href=\"http://y\">x</a> <a class=\"anchor\" href=\"#a&quot;b\" aria-hidden=\"true\">#</a></h2>\n");
+
+        options.header_ids = false;
+        let html = Marked::new(None).parse("## Setup\n", Some(options), None);
+        pretty_assertions::assert_eq!(html, "<h2>Setup</h2>\n");
+    }
+}
@@ -70,7 +70,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -90,7 +91,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             },
             token::Token {
                 _type: "space",
@@ -116,7 +118,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             },
             token::Token {
                 _type: "paragraph",
@@ -149,7 +152,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -169,7 +173,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             },
         ];
         let options = get_default_options();
@@ -206,7 +211,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -251,7 +257,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -271,7 +278,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -309,7 +317,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -379,7 +388,8 @@ mod parser {
                                     align: vec![],
                                     rows: vec![],
                                     header: vec![],
-                                    code_block_style: "".to_string()
+                                    code_block_style: "".to_string(),
+                                    attrs: vec![]
                                 }
                             ],
                             tag: "".to_string(),
@@ -399,7 +409,8 @@ mod parser {
                             align: vec![],
                             rows: vec![],
                             header: vec![],
-                            code_block_style: "".to_string()
+                            code_block_style: "".to_string(),
+                            attrs: vec![]
                         },
                         token::Token {
                             _type: "",
@@ -432,7 +443,8 @@ mod parser {
                                     align: vec![],
                                     rows: vec![],
                                     header: vec![],
-                                    code_block_style: "".to_string()
+                                    code_block_style: "".to_string(),
+                                    attrs: vec![]
                                 }
                             ],
                             tag: "".to_string(),
@@ -452,7 +464,8 @@ mod parser {
                             align: vec![],
                             rows: vec![],
                             header: vec![],
-                            code_block_style: "".to_string()
+                            code_block_style: "".to_string(),
+                            attrs: vec![]
                         }
                     ]
                 ],
@@ -488,7 +501,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -508,7 +522,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     },
                     token::Token {
                         _type: "",
@@ -541,7 +556,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -561,10 +577,12 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -617,7 +635,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -637,7 +656,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -657,7 +677,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -723,7 +744,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -743,7 +765,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -763,7 +786,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     },
                     token::Token {
                         _type: "",
@@ -803,7 +827,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -823,7 +848,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -843,7 +869,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 depth: 0,
@@ -857,7 +884,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -923,7 +951,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -943,7 +972,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -963,7 +993,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     },
                     token::Token {
                         _type: "",
@@ -1003,7 +1034,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -1023,7 +1055,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -1043,7 +1076,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 depth: 0,
@@ -1057,7 +1091,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1123,7 +1158,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -1143,7 +1179,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -1163,7 +1200,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     },
                     token::Token {
                         _type: "",
@@ -1203,7 +1241,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -1223,7 +1262,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -1243,7 +1283,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 depth: 0,
@@ -1257,7 +1298,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1323,7 +1365,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -1343,7 +1386,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -1363,7 +1407,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     },
                     token::Token {
                         _type: "",
@@ -1403,7 +1448,8 @@ mod parser {
                                         align: vec![],
                                         rows: vec![],
                                         header: vec![],
-                                        code_block_style: "".to_string()
+                                        code_block_style: "".to_string(),
+                                        attrs: vec![]
                                     }
                                 ],
                                 tag: "".to_string(),
@@ -1423,7 +1469,8 @@ mod parser {
                                 align: vec![],
                                 rows: vec![],
                                 header: vec![],
-                                code_block_style: "".to_string()
+                                code_block_style: "".to_string(),
+                                attrs: vec![]
                             }
                         ],
                         tag: "".to_string(),
@@ -1443,7 +1490,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 depth: 0,
@@ -1457,7 +1505,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1496,7 +1545,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1542,7 +1592,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -1562,7 +1613,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1601,7 +1653,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             },
             token::Token {
                 _type: "text",
@@ -1627,7 +1680,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1668,7 +1722,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1707,7 +1762,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             },
             token::Token {
                 _type: "text",
@@ -1733,7 +1789,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             },
             token::Token {
                 _type: "html",
@@ -1759,7 +1816,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1806,7 +1864,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -1826,7 +1885,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1865,7 +1925,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1911,7 +1972,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -1931,7 +1993,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -1970,7 +2033,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -2009,7 +2073,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -2055,7 +2120,8 @@ mod parser {
                         align: vec![],
                         rows: vec![],
                         header: vec![],
-                        code_block_style: "".to_string()
+                        code_block_style: "".to_string(),
+                        attrs: vec![]
                     }
                 ],
                 tag: "".to_string(),
@@ -2075,7 +2141,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
@@ -2114,7 +2181,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             },
             token::Token {
                 _type: "text",
@@ -2140,7 +2208,8 @@ mod parser {
                 align: vec![],
                 rows: vec![],
                 header: vec![],
-                code_block_style: "".to_string()
+                code_block_style: "".to_string(),
+                attrs: vec![]
             }
         ];
 
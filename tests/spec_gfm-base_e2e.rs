@@ -55,6 +55,7 @@ macro_rules! spec_test {
                     options.gfm = true;
                     options.pedantic = false;
                     options.header_ids = false;
+                    options.tagfilter = false;
 
                     let expected_std_html = &spec.html;
                     let expected_marked_html = &spec.marked;